@@ -0,0 +1,1339 @@
+//! Command-line interface: parses the arguments and wires them to the loaders and statistics.
+
+use crate::configuration::events_configuration::load_configuration;
+use crate::configuration::events_mapping::{load_mappings, validate_mappings};
+use crate::configuration::history_manifest::{load_manifest, scan_directory};
+use crate::configuration::identity_overrides::load_identity_overrides;
+use crate::configuration::import_configuration::load_import_configuration;
+use crate::configuration::planning::load_planning;
+use crate::configuration::programme::{generate_programme_file, load_programme};
+use crate::error;
+use crate::error::ApplicationError;
+use crate::registration::convention::{load_convention, Convention};
+use crate::registration::diagnostic::{export_diagnostics, Diagnostic, LoadingMode, Severity};
+use crate::registration::history::ConventionHistory;
+use crate::registration::identity;
+use crate::registration::identity::{IdentityResolver, RiderIdentities};
+use crate::registration::loading_options::LoadingOptions;
+use crate::registration::spreadsheet::SheetSelection;
+use crate::statistics::events_registrants_dependency::generate_csv_file;
+use crate::statistics::gender_repartition::draw_and_export_graph;
+use crate::statistics::age_brackets::AgeBrackets;
+use crate::statistics::age_distribution;
+use crate::statistics::age_pyramid::{compute_age_pyramid, draw_and_export_pyramid, PyramidScope};
+use crate::statistics::clubs;
+use crate::statistics::co_registration;
+use crate::statistics::co_registration::CouplingMetric;
+use crate::statistics::co_registration_heatmap;
+use crate::statistics::co_registration_heatmap::HeatmapOrder;
+use crate::statistics::event_families;
+use crate::statistics::events_per_registrant;
+use crate::statistics::participation_trends;
+use crate::statistics::retention;
+use crate::statistics::schedule_conflicts;
+use crate::statistics::schedule_solver;
+use crate::statistics::team_rosters;
+use chrono::NaiveDate;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::fs::{create_dir_all, read_dir, write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Statistics over the registrations to the French unicycling convention.")]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Export, for each couple of events, how many registrants they share as a CSV table.
+    Dependencies {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// CSV file to write.
+        #[arg(short, long, default_value = "dependencies.csv")]
+        output: PathBuf,
+    },
+    /// Export a metric between every couple of events as a CSV matrix,
+    /// along with the most coupled couples of events and all their metrics.
+    CoRegistration {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// Metric of the matrix, and by which couples of events are ranked.
+        #[arg(long, value_enum, default_value_t = MetricArg::Jaccard)]
+        metric: MetricArg,
+        /// Number of couples of events ranked.
+        #[arg(short, long, default_value_t = 20)]
+        top: usize,
+        /// Folder in which the tables are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Draw a metric between every couple of events as a heatmap.
+    Heatmap {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// Metric drawn in each cell.
+        #[arg(long, value_enum, default_value_t = MetricArg::Jaccard)]
+        metric: MetricArg,
+        /// Order of the events along both axes.
+        #[arg(long, value_enum, default_value_t = OrderArg::Events)]
+        order: OrderArg,
+        /// Image file to write, as SVG if its extension is `svg`, PNG otherwise.
+        #[arg(short, long, default_value = "co-registration.png")]
+        output: PathBuf,
+    },
+    /// Cluster the events into families of riders by their shared participants, compared with the official categories,
+    /// as a CSV table along with a dendrogram. Requires a mapping, categories being known from canonical ids.
+    #[command(mut_arg("mapping", |mapping| mapping.required(true)))]
+    Families {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// Number of families the events are split into.
+        #[arg(long, default_value_t = 6)]
+        families: usize,
+        /// Folder in which the table and the graph are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export the couples of events of a programme that riders registered to both cannot attend,
+    /// because they overlap or are too close, as a CSV table.
+    Conflicts {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// Programme, giving the day, times and venue of each event, along with transfer times between venues.
+        /// Events are referred to by their canonical id. Requires a mapping.
+        #[arg(short, long, requires = "mapping")]
+        programme: PathBuf,
+        /// CSV file to write.
+        #[arg(short, long, default_value = "conflicts.csv")]
+        output: PathBuf,
+    },
+    /// Suggest a programme assigning the events to the slots of a planning, keeping as few riders as possible
+    /// from attending all their events, along with the conflicts left as a CSV table.
+    Schedule {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// Planning, giving the available slots, the events to schedule and their constraints.
+        /// Events are referred to by their canonical id. Requires a mapping.
+        #[arg(short, long, requires = "mapping")]
+        planning: PathBuf,
+        /// Seed of the search: the same seed always gives the same programme.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Moves tried to improve the programme.
+        #[arg(long, default_value_t = 10000)]
+        iterations: usize,
+        /// Folder in which the programme and the conflicts are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Draw the female/male repartition of each event.
+    Gender {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// Year of the convention, used in the caption and the file name.
+        #[arg(short, long)]
+        year: u16,
+        /// Folder in which the graph is saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export the teams, pairs and groups of each event, reconstructed from their members' details.
+    Teams {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// CSV file to write.
+        #[arg(short, long, default_value = "teams.csv")]
+        output: PathBuf,
+    },
+    /// Export how many participants of each event and category fall in each age bracket,
+    /// as a CSV table along with a stacked histogram.
+    Ages {
+        #[command(flatten)]
+        input: ConventionArgs,
+        #[command(flatten)]
+        brackets: AgeBracketsArgs,
+        /// Folder in which the table and the graph are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Draw the age pyramid of the registrants, females on the left and males on the right.
+    Pyramid {
+        #[command(flatten)]
+        input: ConventionArgs,
+        #[command(flatten)]
+        brackets: AgeBracketsArgs,
+        /// Only draw the participants of this event.
+        #[arg(long, conflicts_with = "category")]
+        event: Option<String>,
+        /// Only draw the participants of this category's events. Requires a mapping.
+        #[arg(long, requires = "mapping")]
+        category: Option<String>,
+        /// Folder in which the graph is saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export how many events each registrant entered, the mean and median per gender and age bracket,
+    /// and the registrants who entered no event, as CSV tables along with a histogram.
+    EventsCount {
+        #[command(flatten)]
+        input: ConventionArgs,
+        #[command(flatten)]
+        brackets: AgeBracketsArgs,
+        /// Folder in which the tables and the graph are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export the riders, genders, ages, events and categories of each club as a CSV table,
+    /// along with a ranking chart of the biggest clubs.
+    Clubs {
+        #[command(flatten)]
+        input: ConventionArgs,
+        #[command(flatten)]
+        brackets: AgeBracketsArgs,
+        /// Number of clubs drawn in the ranking chart.
+        #[arg(short, long, default_value_t = 20)]
+        top: usize,
+        /// Folder in which the table and the chart are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Report every malformed cell of a registration export.
+    Check {
+        #[command(flatten)]
+        input: ConventionArgs,
+        /// CSV file to write the report to, in addition to printing it.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Summarize every year of an archive of conventions.
+    History {
+        #[command(flatten)]
+        input: HistoryArgs,
+    },
+    /// Match registrants across years, and export the rider id of each registration as a CSV table.
+    Riders {
+        #[command(flatten)]
+        input: HistoryArgs,
+        #[command(flatten)]
+        identity: IdentityArgs,
+        /// CSV file to write.
+        #[arg(short, long, default_value = "riders.csv")]
+        output: PathBuf,
+    },
+    /// Export how many riders come back each year, the survival of each cohort and the churn
+    /// by age and gender, as CSV tables along with the cohorts' survival curves.
+    Retention {
+        #[command(flatten)]
+        input: HistoryArgs,
+        #[command(flatten)]
+        identity: IdentityArgs,
+        #[command(flatten)]
+        brackets: AgeBracketsArgs,
+        /// Folder in which the tables and the chart are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Export the registrants and participations of each year, per category and per event,
+    /// as a CSV table along with line charts.
+    Trends {
+        #[command(flatten)]
+        input: HistoryArgs,
+        /// Events configuration, listing categories and their events.
+        #[arg(short, long, default_value = "configuration/events.yml")]
+        events: PathBuf,
+        /// Folder in which the table and the charts are saved.
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+    },
+    /// Check the events configuration and the yearly mappings are consistent.
+    ValidateConfig {
+        /// Events configuration, listing categories and their events.
+        #[arg(short, long, default_value = "configuration/events.yml")]
+        events: PathBuf,
+        /// Yearly mapping files to check.
+        /// Defaults to every `<year>.yml` file next to the events configuration.
+        mappings: Vec<PathBuf>,
+    },
+}
+
+/// Where and how to load a convention from.
+#[derive(Debug, Args)]
+struct ConventionArgs {
+    /// Registrations export of the convention (XLS, XLSX, XLSB, ODS or CSV).
+    file: PathBuf,
+    /// Yearly mapping file, to compute statistics on canonical events instead of raw columns.
+    #[arg(short, long)]
+    mapping: Option<PathBuf>,
+    /// Compute statistics on categories instead of events, a registrant counting once per category.
+    /// Requires a mapping.
+    #[arg(long, requires = "mapping")]
+    by_category: bool,
+    /// Events configuration, listing categories and their events.
+    #[arg(long, default_value = "configuration/events.yml")]
+    events: PathBuf,
+    /// Name of the sheet holding the registrations.
+    #[arg(long, default_value = "Worksheet1", conflicts_with = "first_sheet")]
+    sheet: String,
+    /// Read the registrations from the first sheet, whatever its name.
+    #[arg(long)]
+    first_sheet: bool,
+    /// Layout of the export (column headers...), when it differs from the registration platform's.
+    #[arg(long)]
+    import: Option<PathBuf>,
+    /// Leave malformed rows out and report them, instead of failing.
+    #[arg(long)]
+    lenient: bool,
+    /// Start date of the convention (YYYY-MM-DD), to compute the registrants' age instead of trusting the export.
+    #[arg(long)]
+    start_date: Option<NaiveDate>,
+}
+
+impl ConventionArgs {
+    fn loading_options(&self) -> error::Result<LoadingOptions> {
+        let sheet = if self.first_sheet {
+            SheetSelection::First
+        } else {
+            SheetSelection::Named(self.sheet.clone())
+        };
+        let mode = if self.lenient {
+            LoadingMode::Lenient
+        } else {
+            LoadingMode::Strict
+        };
+        let mut options = LoadingOptions::default().with_sheet(sheet).with_mode(mode);
+        if let Some(start_date) = self.start_date {
+            options = options.with_start_date(start_date);
+        }
+        if let Some(import) = &self.import {
+            options = options.with_import(load_import_configuration(import)?);
+        }
+
+        Ok(options)
+    }
+
+    /// Load the convention, normalizing its events if a mapping is given,
+    /// then gathering them into categories if requested.
+    /// Diagnostics of a lenient loading are printed as warnings.
+    fn load(&self) -> error::Result<Convention> {
+        let (convention, diagnostics) = load_convention(&self.file, &self.loading_options()?)?;
+        for diagnostic in diagnostics {
+            eprintln!("{diagnostic}");
+        }
+        self.normalize(convention)
+    }
+
+    fn normalize(&self, convention: Convention) -> error::Result<Convention> {
+        let convention = match &self.mapping {
+            Some(mapping) => convention.normalize(&load_mappings(mapping)?)?,
+            None => convention,
+        };
+        if self.by_category {
+            convention.aggregate_by_category(&load_configuration(&self.events)?)
+        } else {
+            Ok(convention)
+        }
+    }
+}
+
+/// Where and how to load several years of conventions from.
+#[derive(Debug, Args)]
+struct HistoryArgs {
+    /// Manifest listing each year's export and mapping,
+    /// or folder of exports named after their year (`2016.xls`, `2017.csv`...).
+    source: PathBuf,
+    /// Folder of the `<year>.yml` mappings, when loading a folder of exports.
+    #[arg(long, default_value = "configuration")]
+    mappings: PathBuf,
+    /// Read the registrations from the first sheet of each export, whatever its name.
+    #[arg(long)]
+    first_sheet: bool,
+    /// Layout of the exports (column headers...), when it differs from the registration platform's.
+    #[arg(long)]
+    import: Option<PathBuf>,
+    /// Leave malformed rows out and report them, instead of failing.
+    #[arg(long)]
+    lenient: bool,
+}
+
+impl HistoryArgs {
+    /// Load every year, normalized through its mapping.
+    /// Diagnostics of a lenient loading are printed as warnings.
+    fn load(&self) -> error::Result<ConventionHistory> {
+        let manifest = if self.source.is_dir() {
+            scan_directory(&self.source, &self.mappings)?
+        } else {
+            load_manifest(&self.source)?
+        };
+
+        let mut options = LoadingOptions::default();
+        if self.first_sheet {
+            options = options.with_sheet(SheetSelection::First);
+        }
+        if self.lenient {
+            options = options.with_mode(LoadingMode::Lenient);
+        }
+        if let Some(import) = &self.import {
+            options = options.with_import(load_import_configuration(import)?);
+        }
+
+        let (history, diagnostics) = ConventionHistory::load(&manifest, &options)?;
+        for (year, diagnostic) in diagnostics {
+            eprintln!("{year}: {diagnostic}");
+        }
+        Ok(history)
+    }
+}
+
+/// How to recognize riders from one year to the next.
+#[derive(Debug, Args)]
+struct IdentityArgs {
+    /// Registrations known to be the same rider, or different riders, whatever their names.
+    #[arg(long)]
+    overrides: Option<PathBuf>,
+    /// Typos tolerated between the names of registrants born the same day.
+    #[arg(long, default_value_t = 2)]
+    max_distance: usize,
+}
+
+impl IdentityArgs {
+    fn resolver(&self) -> error::Result<IdentityResolver> {
+        let mut resolver = IdentityResolver::default().with_max_distance(self.max_distance);
+        if let Some(overrides) = &self.overrides {
+            resolver = resolver.with_overrides(load_identity_overrides(overrides)?);
+        }
+
+        Ok(resolver)
+    }
+
+    fn resolve(&self, history: &ConventionHistory) -> error::Result<RiderIdentities> {
+        let (identities, diagnostics) = self.resolver()?.resolve(history);
+        for (year, diagnostic) in diagnostics {
+            eprintln!("{year}: {diagnostic}");
+        }
+        Ok(identities)
+    }
+}
+
+/// How to gather registrants by age.
+#[derive(Debug, Args)]
+struct AgeBracketsArgs {
+    /// Lower bound of each age bracket, e.g. `11,13,15,17,19,30`. Defaults to the IUF age groups.
+    #[arg(long, value_delimiter = ',')]
+    brackets: Vec<u8>,
+}
+
+impl AgeBracketsArgs {
+    fn brackets(&self) -> AgeBrackets {
+        if self.brackets.is_empty() {
+            AgeBrackets::default()
+        } else {
+            AgeBrackets::new(self.brackets.clone())
+        }
+    }
+}
+
+/// Metric events are compared with, see [CouplingMetric].
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum MetricArg {
+    /// Registrants entering both events.
+    Shared,
+    /// Share of the row event's participants entering the column event.
+    Conditional,
+    /// Shared participants among the participants of either event.
+    Jaccard,
+    /// How much more often both events are entered together than if registrants picked them independently.
+    Lift,
+}
+
+impl From<MetricArg> for CouplingMetric {
+    fn from(metric: MetricArg) -> Self {
+        match metric {
+            MetricArg::Shared => CouplingMetric::Shared,
+            MetricArg::Conditional => CouplingMetric::Conditional,
+            MetricArg::Jaccard => CouplingMetric::Jaccard,
+            MetricArg::Lift => CouplingMetric::Lift,
+        }
+    }
+}
+
+/// Order of the events along both axes of a heatmap, see [HeatmapOrder].
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OrderArg {
+    /// Order of the convention's events.
+    Events,
+    /// Events of a category next to each other, the most popular first.
+    Category,
+    /// Events often entered together next to each other.
+    Clustering,
+}
+
+impl From<OrderArg> for HeatmapOrder {
+    fn from(order: OrderArg) -> Self {
+        match order {
+            OrderArg::Events => HeatmapOrder::Events,
+            OrderArg::Category => HeatmapOrder::Category,
+            OrderArg::Clustering => HeatmapOrder::Clustering,
+        }
+    }
+}
+
+/// Execute the requested command.
+pub fn run(cli: Cli) -> error::Result<()> {
+    match cli.command {
+        Command::Dependencies { input, output } => {
+            let content = generate_csv_file(&input.load()?);
+            write(&output, content).map_err(|source| io_error(&output, source))?;
+            println!("Dependencies written to `{}`.", output.display());
+        }
+        Command::CoRegistration {
+            input,
+            metric,
+            top,
+            output,
+        } => {
+            let co_registration = co_registration::compute_co_registration(&input.load()?);
+            let metric = metric.into();
+            let pairs = co_registration.top_pairs(metric, top);
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            for (file, content) in [
+                ("co-registration.csv", co_registration::generate_matrix_csv_file(&co_registration, metric)),
+                ("coupled-events.csv", co_registration::generate_pairs_csv_file(&co_registration, &pairs)),
+            ] {
+                let file = output.join(file);
+                write(&file, content).map_err(|source| io_error(&file, source))?;
+            }
+            for pair in &pairs {
+                println!(
+                    "{} & {}: {} shared registrants, Jaccard {:.2}, lift {:.2}.",
+                    co_registration.events()[*pair.first()],
+                    co_registration.events()[*pair.second()],
+                    pair.shared(),
+                    pair.jaccard().unwrap_or_default(),
+                    pair.lift().unwrap_or_default()
+                );
+            }
+            println!("Co-registration written to `{}`.", output.display());
+        }
+        Command::Heatmap {
+            input,
+            metric,
+            order,
+            output,
+        } => {
+            let co_registration = co_registration::compute_co_registration(&input.load()?);
+            let order = co_registration_heatmap::order_events(&co_registration, order.into());
+            co_registration_heatmap::draw_and_export_heatmap(&co_registration, metric.into(), &order, &output)?;
+            println!("Heatmap written to `{}`.", output.display());
+        }
+        Command::Families {
+            input,
+            families,
+            output,
+        } => {
+            let families = event_families::compute_event_families(&input.load()?, &load_configuration(&input.events)?, families);
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            let file = output.join("families.csv");
+            write(&file, event_families::generate_csv_file(&families)).map_err(|source| io_error(&file, source))?;
+            event_families::draw_and_export_dendrogram(&families, &output.join("dendrogram.png"))?;
+            for family in 0..families.families_count() {
+                let events = families.families().iter().filter(|event_family| **event_family == family).count();
+                match families.main_category(family) {
+                    Some((category, share)) => {
+                        println!("Family {}: {events} events, {:.0}% of them {category}.", family + 1, share * 100.0)
+                    }
+                    None => println!("Family {}: {events} events.", family + 1),
+                }
+            }
+            if let Some(rand_index) = families.rand_index() {
+                println!("Families and categories agree on {:.0}% of the couples of events.", rand_index * 100.0);
+            }
+            println!("Families written to `{}`.", output.display());
+        }
+        Command::Conflicts {
+            input,
+            programme,
+            output,
+        } => {
+            let report = schedule_conflicts::find_conflicts(&input.load()?, &load_programme(&programme)?)?;
+            for event in report.unscheduled() {
+                eprintln!("Event `{event}` is not in the programme.");
+            }
+            write(&output, schedule_conflicts::generate_csv_file(&report)).map_err(|source| io_error(&output, source))?;
+            println!("{} conflicts written to `{}`.", report.conflicts().len(), output.display());
+        }
+        Command::Schedule {
+            input,
+            planning,
+            seed,
+            iterations,
+            output,
+        } => {
+            let convention = input.load()?;
+            let programme = schedule_solver::suggest_programme(&convention, &load_planning(&planning)?, seed, iterations)?;
+            let report = schedule_conflicts::find_conflicts(&convention, &programme)?;
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            for (file, content) in [
+                ("programme.yml", generate_programme_file(&programme)),
+                ("conflicts.csv", schedule_conflicts::generate_csv_file(&report)),
+            ] {
+                let file = output.join(file);
+                write(&file, content).map_err(|source| io_error(&file, source))?;
+            }
+            println!(
+                "Programme written to `{}`: {} riders left with clashing events.",
+                output.display(),
+                report.riders().len()
+            );
+        }
+        Command::Gender {
+            input,
+            year,
+            output,
+        } => {
+            let convention = input.load()?;
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            draw_and_export_graph(&convention, year, &output)?;
+            println!("Graph written to `{}`.", output.join(format!("{year}.png")).display());
+        }
+        Command::Teams { input, output } => {
+            let content = team_rosters::generate_csv_file(&input.load()?);
+            write(&output, content).map_err(|source| io_error(&output, source))?;
+            println!("Teams written to `{}`.", output.display());
+        }
+        Command::Ages {
+            input,
+            brackets,
+            output,
+        } => {
+            let brackets = brackets.brackets();
+            let distribution = age_distribution::compute_age_distribution(&input.load()?, &brackets);
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            let table = output.join("ages.csv");
+            write(&table, age_distribution::generate_csv_file(&distribution, &brackets)).map_err(|source| io_error(&table, source))?;
+            age_distribution::draw_and_export_graph(&distribution, &brackets, &output)?;
+            println!("Age distribution written to `{}`.", output.display());
+        }
+        Command::Pyramid {
+            input,
+            brackets,
+            event,
+            category,
+            output,
+        } => {
+            let (scope, caption) = match (event, category) {
+                (Some(event), _) => (PyramidScope::Event(event.clone()), format!("Pyramide des âges ({event})")),
+                (_, Some(category)) => (PyramidScope::Category(category.clone()), format!("Pyramide des âges ({category})")),
+                (None, None) => (PyramidScope::Convention, "Pyramide des âges".to_string()),
+            };
+            let brackets = brackets.brackets();
+            let pyramid = compute_age_pyramid(&input.load()?, &brackets, &scope)?;
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            draw_and_export_pyramid(&pyramid, &brackets, &caption, &output)?;
+            println!("Pyramid written to `{}`.", output.join("pyramid.png").display());
+        }
+        Command::EventsCount {
+            input,
+            brackets,
+            output,
+        } => {
+            let brackets = brackets.brackets();
+            let statistics = events_per_registrant::compute_events_per_registrant(&input.load()?, &brackets);
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            for (file, content) in [
+                ("events-count.csv", events_per_registrant::generate_histogram_csv_file(&statistics)),
+                ("events-count-summary.csv", events_per_registrant::generate_summary_csv_file(&statistics, &brackets)),
+                ("idle-registrants.csv", events_per_registrant::generate_idle_csv_file(&statistics)),
+            ] {
+                let file = output.join(file);
+                write(&file, content).map_err(|source| io_error(&file, source))?;
+            }
+            events_per_registrant::draw_and_export_histogram(&statistics, &output)?;
+            if !statistics.idle().is_empty() {
+                eprintln!("{} registrants entered no event.", statistics.idle().len());
+            }
+            println!("Events per registrant written to `{}`.", output.display());
+        }
+        Command::Clubs {
+            input,
+            brackets,
+            top,
+            output,
+        } => {
+            let brackets = brackets.brackets();
+            let report = clubs::compute_club_report(&input.load()?, &brackets);
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            let table = output.join("clubs.csv");
+            write(&table, clubs::generate_csv_file(&report, &brackets)).map_err(|source| io_error(&table, source))?;
+            clubs::draw_and_export_leaderboard(&report, top, &output)?;
+            println!("{} clubs written to `{}`.", report.clubs().len(), output.display());
+        }
+        Command::Check { input, output } => {
+            let options = input.loading_options()?.with_mode(LoadingMode::Lenient);
+            let (convention, diagnostics) = load_convention(&input.file, &options)?;
+            input.normalize(convention)?;
+            report_diagnostics(&diagnostics, output.as_deref())?;
+        }
+        Command::History { input } => {
+            for (year, convention) in input.load()?.conventions() {
+                println!(
+                    "{year}: {} registrants, {} events.",
+                    convention.registrations().len(),
+                    convention.events().len()
+                );
+            }
+        }
+        Command::Riders {
+            input,
+            identity,
+            output,
+        } => {
+            let history = input.load()?;
+            let identities = identity.resolve(&history)?;
+            write(&output, identity::generate_csv_file(&history, &identities)).map_err(|source| io_error(&output, source))?;
+            println!("{} riders written to `{}`.", identities.riders_count(), output.display());
+        }
+        Command::Retention {
+            input,
+            identity,
+            brackets,
+            output,
+        } => {
+            let history = input.load()?;
+            let identities = identity.resolve(&history)?;
+            let brackets = brackets.brackets();
+            let report = retention::compute_retention(&history, &identities, &brackets);
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            for (file, content) in [
+                ("retention.csv", retention::generate_retention_csv_file(&report)),
+                ("cohorts.csv", retention::generate_cohorts_csv_file(&report)),
+                ("churn.csv", retention::generate_churn_csv_file(&report, &brackets)),
+            ] {
+                let file = output.join(file);
+                write(&file, content).map_err(|source| io_error(&file, source))?;
+            }
+            retention::draw_and_export_survival_curves(&report, &output)?;
+            println!("Retention written to `{}`.", output.display());
+        }
+        Command::Trends {
+            input,
+            events,
+            output,
+        } => {
+            let configuration = load_configuration(&events)?;
+            let trends = participation_trends::compute_trends(&input.load()?, &configuration)?;
+            create_dir_all(&output).map_err(|source| io_error(&output, source))?;
+            let table = output.join("trends.csv");
+            write(&table, participation_trends::generate_csv_file(&trends)).map_err(|source| io_error(&table, source))?;
+            participation_trends::draw_and_export_graphs(&trends, &configuration, &output)?;
+            println!("Trends written to `{}`.", output.display());
+        }
+        Command::ValidateConfig { events, mappings } => {
+            let configuration = load_configuration(&events)?;
+            let mappings = if mappings.is_empty() {
+                find_mapping_files(&events)?
+            } else {
+                mappings
+            };
+
+            for mapping in mappings {
+                validate_mappings(&configuration, &load_mappings(&mapping)?)?;
+                println!("`{}` is valid.", mapping.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn report_diagnostics(diagnostics: &[Diagnostic], output: Option<&Path>) -> error::Result<()> {
+    for diagnostic in diagnostics {
+        println!("{diagnostic}");
+    }
+    if let Some(output) = output {
+        export_diagnostics(diagnostics, output)?;
+        println!("Report written to `{}`.", output.display());
+    }
+
+    let errors_count = diagnostics
+        .iter()
+        .filter(|diagnostic| *diagnostic.severity() == Severity::Error)
+        .count();
+    if diagnostics.is_empty() {
+        println!("No problem found.");
+        Ok(())
+    } else if errors_count == 0 {
+        println!("{} warning(s) found.", diagnostics.len());
+        Ok(())
+    } else {
+        Err(ApplicationError::InvalidRegistrations(errors_count))
+    }
+}
+
+/// Exit code matching the error, following the `sysexits.h` conventions.
+pub fn exit_code(error: &ApplicationError) -> ExitCode {
+    match error {
+        ApplicationError::Sheet(_)
+        | ApplicationError::Csv(_)
+        | ApplicationError::Deserialization(_)
+        | ApplicationError::NoSheet
+        | ApplicationError::NoHeaders
+        | ApplicationError::MissingColumn { .. }
+        | ApplicationError::MisformattedRow
+        | ApplicationError::InvalidCell(_)
+        | ApplicationError::InvalidRegistrations(_)
+        | ApplicationError::WrongFormat(_)
+        | ApplicationError::UnmappedEvent(_)
+        | ApplicationError::UnknownEvent(_)
+        | ApplicationError::AmbiguousEvent(_)
+        | ApplicationError::AmbiguousHeader(_)
+        | ApplicationError::AmbiguousColumn { .. } => ExitCode::from(65),
+        ApplicationError::Drawing(_) => ExitCode::from(70),
+        ApplicationError::Io { .. } => ExitCode::from(74),
+        ApplicationError::Configuration(_) => ExitCode::from(78),
+    }
+}
+
+fn find_mapping_files(events: &Path) -> error::Result<Vec<PathBuf>> {
+    let folder = events.parent().unwrap_or(Path::new("."));
+    let entries = read_dir(folder).map_err(|source| io_error(folder, source))?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "yml"))
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.parse::<u16>().is_ok())
+        })
+        .collect();
+    files.sort();
+
+    Ok(files)
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> ApplicationError {
+    ApplicationError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod run {
+        use crate::cli::{run, Cli};
+        use crate::configuration::programme::load_programme;
+        use crate::error::ApplicationError;
+        use crate::test_data::get_test_asset;
+        use clap::error::ErrorKind;
+        use clap::Parser;
+        use std::env::temp_dir;
+
+        #[test]
+        fn success_dependencies() {
+            let output = temp_dir().join("cli-dependencies.csv");
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from([
+                "stats",
+                "dependencies",
+                file.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.exists());
+        }
+
+        #[test]
+        fn success_dependencies_with_mapping() {
+            let output = temp_dir().join("cli-dependencies-mapping.csv");
+            let file = get_test_asset("registration/registrations.xls");
+            let mapping = format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR"));
+            let cli = Cli::parse_from([
+                "stats",
+                "dependencies",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            let content = std::fs::read_to_string(output).unwrap();
+            assert!(content.contains("tout-terrain/cross-country"));
+        }
+
+        #[test]
+        fn success_dependencies_by_category() {
+            let output = temp_dir().join("cli-dependencies-category.csv");
+            let file = get_test_asset("registration/registrations.xls");
+            let mapping = format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR"));
+            let events = format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR"));
+            let cli = Cli::parse_from([
+                "stats",
+                "dependencies",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "--by-category",
+                "--events",
+                &events,
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            let content = std::fs::read_to_string(output).unwrap();
+            assert!(content.starts_with(";Artistique;Athlétisme;"));
+            assert!(!content.contains("tout-terrain/cross-country"));
+        }
+
+        #[test]
+        fn success_teams() {
+            let output = temp_dir().join("cli-teams.csv");
+            let file = get_test_asset("registration/registrations.csv");
+            let cli = Cli::parse_from([
+                "stats",
+                "teams",
+                file.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            let content = std::fs::read_to_string(output).unwrap();
+            assert!(content.contains("Victor Bègue & Dominique Toussaint"));
+        }
+
+        #[test]
+        fn success_history() {
+            let manifest = get_test_asset("history/manifest.yml");
+            let cli = Cli::parse_from(["stats", "history", manifest.to_str().unwrap()]);
+
+            run(cli).unwrap();
+        }
+
+        #[test]
+        fn success_history_directory() {
+            let exports = get_test_asset("history/exports");
+            let mappings = get_test_asset("history/mappings");
+            let cli = Cli::parse_from([
+                "stats",
+                "history",
+                exports.to_str().unwrap(),
+                "--mappings",
+                mappings.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+        }
+
+        #[test]
+        fn success_riders() {
+            let output = temp_dir().join("cli-riders.csv");
+            let manifest = get_test_asset("history/manifest.yml");
+            let overrides = get_test_asset("history/identity-overrides.yml");
+            let cli = Cli::parse_from([
+                "stats",
+                "riders",
+                manifest.to_str().unwrap(),
+                "--overrides",
+                overrides.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.exists());
+        }
+
+        #[test]
+        fn success_retention() {
+            let output = temp_dir().join("cli-retention");
+            let manifest = get_test_asset("history/manifest.yml");
+            let cli = Cli::parse_from([
+                "stats",
+                "retention",
+                manifest.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("retention.csv").exists());
+            assert!(output.join("cohorts.csv").exists());
+            assert!(output.join("churn.csv").exists());
+            assert!(output.join("cohorts.png").exists());
+        }
+
+        #[test]
+        fn success_trends() {
+            let output = temp_dir().join("cli-trends");
+            let manifest = get_test_asset("history/manifest.yml");
+            let events = format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR"));
+            let cli = Cli::parse_from([
+                "stats",
+                "trends",
+                manifest.to_str().unwrap(),
+                "--events",
+                &events,
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("trends.csv").exists());
+            assert!(output.join("trends.png").exists());
+        }
+
+        #[test]
+        fn success_ages() {
+            let output = temp_dir().join("cli-ages");
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from([
+                "stats",
+                "ages",
+                file.to_str().unwrap(),
+                "--brackets",
+                "18,60",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            let content = std::fs::read_to_string(output.join("ages.csv")).unwrap();
+            assert!(content.starts_with(";0-17;18-59;60+\n"));
+            assert!(output.join("ages.png").exists());
+        }
+
+        #[test]
+        fn success_pyramid_category() {
+            let output = temp_dir().join("cli-pyramid");
+            let file = get_test_asset("registration/registrations.xls");
+            let mapping = format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR"));
+            let cli = Cli::parse_from([
+                "stats",
+                "pyramid",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "--category",
+                "urbain",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("pyramid.png").exists());
+        }
+
+        #[test]
+        fn fail_pyramid_unknown_event() {
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from(["stats", "pyramid", file.to_str().unwrap(), "--event", "Unknown - All"]);
+
+            let error = run(cli).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::UnknownEvent(_)));
+        }
+
+        #[test]
+        fn success_events_count() {
+            let output = temp_dir().join("cli-events-count");
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from(["stats", "events-count", file.to_str().unwrap(), "-o", output.to_str().unwrap()]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("events-count.csv").exists());
+            assert!(output.join("events-count-summary.csv").exists());
+            assert!(output.join("idle-registrants.csv").exists());
+            assert!(output.join("events-count.png").exists());
+        }
+
+        #[test]
+        fn success_clubs() {
+            let output = temp_dir().join("cli-clubs");
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from(["stats", "clubs", file.to_str().unwrap(), "--top", "5", "-o", output.to_str().unwrap()]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("clubs.csv").exists());
+            assert!(output.join("clubs.png").exists());
+        }
+
+        #[test]
+        fn success_co_registration() {
+            let output = temp_dir().join("cli-co-registration");
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from([
+                "stats",
+                "co-registration",
+                file.to_str().unwrap(),
+                "--metric",
+                "lift",
+                "--top",
+                "5",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("co-registration.csv").exists());
+            let pairs = std::fs::read_to_string(output.join("coupled-events.csv")).unwrap();
+            assert_eq!(6, pairs.lines().count());
+        }
+
+        #[test]
+        fn success_heatmap() {
+            let output = temp_dir().join("cli-heatmap.svg");
+            let file = get_test_asset("registration/registrations.xls");
+            let mapping = format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR"));
+            let cli = Cli::parse_from([
+                "stats",
+                "heatmap",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "--order",
+                "category",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.exists());
+        }
+
+        #[test]
+        fn success_families() {
+            let output = temp_dir().join("cli-families");
+            let file = get_test_asset("registration/registrations.xls");
+            let root = env!("CARGO_MANIFEST_DIR");
+            let (mapping, events) = (format!("{root}/configuration/2016.yml"), format!("{root}/configuration/events.yml"));
+            let cli = Cli::parse_from([
+                "stats",
+                "families",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "--events",
+                &events,
+                "--families",
+                "4",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("families.csv").exists());
+            assert!(output.join("dendrogram.png").exists());
+        }
+
+        #[test]
+        fn fail_families_without_mapping() {
+            let file = get_test_asset("registration/registrations.xls");
+
+            let error = Cli::try_parse_from(["stats", "families", file.to_str().unwrap()]).unwrap_err();
+
+            assert_eq!(ErrorKind::MissingRequiredArgument, error.kind());
+        }
+
+        #[test]
+        fn success_conflicts() {
+            let output = temp_dir().join("cli-conflicts.csv");
+            let file = get_test_asset("registration/registrations.xls");
+            let mapping = format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR"));
+            let programme = get_test_asset("configuration/programme.yml");
+            let cli = Cli::parse_from([
+                "stats",
+                "conflicts",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "--programme",
+                programme.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            let content = std::fs::read_to_string(output).unwrap();
+            assert_eq!(3, content.lines().count());
+        }
+
+        #[test]
+        fn success_schedule() {
+            let output = temp_dir().join("cli-schedule");
+            let mapping = format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR"));
+            let file = get_test_asset("registration/registrations.xls");
+            let planning = get_test_asset("configuration/planning.yml");
+            let cli = Cli::parse_from([
+                "stats",
+                "schedule",
+                file.to_str().unwrap(),
+                "--mapping",
+                &mapping,
+                "--planning",
+                planning.to_str().unwrap(),
+                "--seed",
+                "3",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            let programme = load_programme(&output.join("programme.yml")).unwrap();
+            assert_eq!(5, programme.slots().len());
+            assert!(output.join("conflicts.csv").exists());
+        }
+
+        #[test]
+        fn success_gender() {
+            let output = temp_dir().join("cli-gender");
+            let file = get_test_asset("registration/registrations.xls");
+            let cli = Cli::parse_from([
+                "stats",
+                "gender",
+                file.to_str().unwrap(),
+                "--year",
+                "2024",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("2024.png").exists());
+        }
+
+        #[test]
+        fn success_gender_first_sheet() {
+            let output = temp_dir().join("cli-gender-first-sheet");
+            let file = get_test_asset("registration/wrong_sheet_name.xls");
+            let cli = Cli::parse_from([
+                "stats",
+                "gender",
+                file.to_str().unwrap(),
+                "--first-sheet",
+                "--year",
+                "2023",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("2023.png").exists());
+        }
+
+        #[test]
+        fn success_check() {
+            let file = get_test_asset("registration/registrations.xlsx");
+            let cli = Cli::parse_from(["stats", "check", file.to_str().unwrap()]);
+
+            run(cli).unwrap();
+        }
+
+        #[test]
+        fn success_check_with_warnings() {
+            let file = get_test_asset("registration/registrations.xlsx");
+            let cli = Cli::parse_from(["stats", "check", file.to_str().unwrap(), "--start-date", "2025-05-29"]);
+
+            run(cli).unwrap();
+        }
+
+        #[test]
+        fn fail_check() {
+            let output = temp_dir().join("cli-check.csv");
+            let file = get_test_asset("registration/malformed_registrations.csv");
+            let cli = Cli::parse_from([
+                "stats",
+                "check",
+                file.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            let error = run(cli).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::InvalidRegistrations(3)));
+            assert!(output.exists());
+        }
+
+        #[test]
+        fn success_lenient() {
+            let output = temp_dir().join("cli-lenient");
+            let file = get_test_asset("registration/malformed_registrations.csv");
+            let cli = Cli::parse_from([
+                "stats",
+                "gender",
+                file.to_str().unwrap(),
+                "--lenient",
+                "--year",
+                "2022",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+
+            run(cli).unwrap();
+
+            assert!(output.join("2022.png").exists());
+        }
+
+        #[test]
+        fn success_validate_config() {
+            let events = format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR"));
+            let cli = Cli::parse_from(["stats", "validate-config", "--events", &events]);
+
+            run(cli).unwrap();
+        }
+
+        #[test]
+        fn fail_validate_config() {
+            let events = get_test_asset("configuration/events.yml");
+            let mapping = get_test_asset("configuration/2025.yml");
+            let cli = Cli::parse_from([
+                "stats",
+                "validate-config",
+                "--events",
+                events.to_str().unwrap(),
+                mapping.to_str().unwrap(),
+            ]);
+
+            let error = run(cli).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::Configuration(_)));
+        }
+
+        #[test]
+        fn fail_file_not_found() {
+            let file = get_test_asset("registration/not_found.xls");
+            let cli = Cli::parse_from(["stats", "dependencies", file.to_str().unwrap()]);
+
+            let error = run(cli).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::Sheet(_)));
+        }
+    }
+}
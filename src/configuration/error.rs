@@ -7,4 +7,24 @@ pub type Result<T, E = ConfigurationError> = std::result::Result<T, E>;
 pub enum ConfigurationError {
     #[error(transparent)]
     Load(#[from] ConfigError),
-}
\ No newline at end of file
+    #[error("The mapping refers to an unknown category `{0}`.")]
+    UnknownCategory(String),
+    #[error("The mapping refers to an unknown event `{event}` in category `{category}`.")]
+    UnknownEvent { category: String, event: String },
+    #[error("`{0}` is not a year.")]
+    InvalidYear(String),
+    #[error("`{0}` is not a registration reference, `<year>/<registrant id>` was expected.")]
+    InvalidRegistrationReference(String),
+    #[error("No mapping found for year {0}.")]
+    MissingMapping(u16),
+    #[error("Both `{first}` and `{second}` are exports of year {year}.")]
+    DuplicateYear { year: u16, first: String, second: String },
+    #[error("The slot of event `{0}` does not end after it starts.")]
+    InvalidSlot(String),
+    #[error("The slot `{0}` does not end after it starts.")]
+    InvalidAvailableSlot(String),
+    #[error("No slot of the planning is left for event `{0}`.")]
+    UnschedulableEvent(String),
+    #[error("Cannot access `{path}`: {source}")]
+    Io { path: String, source: std::io::Error },
+}
@@ -1,9 +1,10 @@
 use crate::configuration::error::Result;
+use derive_getters::Getters;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::Path;
 
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
 pub struct EventsConfiguration {
     categories: HashMap<String, EventsCategory>,
 }
@@ -15,7 +16,7 @@ impl EventsConfiguration {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
 pub struct EventsCategory {
     name: String,
     events: HashMap<String, String>,
@@ -28,7 +29,6 @@ impl EventsCategory {
     }
 }
 
-#[allow(dead_code)]
 pub fn load_configuration(path: &Path) -> Result<EventsConfiguration> {
     let settings = config::Config::builder()
         .add_source(config::File::from(path))
@@ -1,12 +1,40 @@
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::path::Path;
 
+use serde::Deserialize;
+
+use crate::configuration::error::ConfigurationError::{UnknownCategory, UnknownEvent};
 use crate::configuration::error::Result;
+use crate::configuration::events_configuration::EventsConfiguration;
 
 pub type CategoriesMapping = HashMap<String, EventsMapping>;
-pub type EventsMapping = HashMap<String, Vec<String>>;
+pub type EventsMapping = HashMap<String, Vec<ColumnSelector>>;
+
+/// Designates a spreadsheet column of an event.
+///
+/// Most of the time, the header is enough. When several columns share the same header
+/// (e.g. `Parcours IUF - All` both in Challenge poussin and in Athlétisme),
+/// the column is designated by its occurrence and/or the column it comes after.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ColumnSelector {
+    Header(String),
+    Disambiguated {
+        header: String,
+        /// 1-based rank among the columns sharing this header (after [ColumnSelector::Disambiguated::after], if any).
+        occurrence: Option<NonZeroUsize>,
+        /// Header of a column preceding the designated one, such as the first event of its section.
+        after: Option<String>,
+    },
+}
+
+impl From<&str> for ColumnSelector {
+    fn from(header: &str) -> Self {
+        ColumnSelector::Header(header.to_string())
+    }
+}
 
-#[allow(dead_code)]
 pub fn load_mappings(path: &Path) -> Result<CategoriesMapping> {
     let settings = config::Config::builder()
         .add_source(config::File::from(path))
@@ -15,18 +43,59 @@ pub fn load_mappings(path: &Path) -> Result<CategoriesMapping> {
     Ok(settings.try_deserialize::<CategoriesMapping>()?)
 }
 
+/// Identifier of an event that stays the same across years, whatever the spreadsheet's column names.
+pub fn canonical_event_id(category: &str, event: &str) -> String {
+    format!("{category}/{event}")
+}
+
+/// Category of a canonical event, as built by [canonical_event_id].
+pub fn canonical_event_category(id: &str) -> Option<&str> {
+    id.split_once('/').map(|(category, _)| category)
+}
+
+/// Ensure every category and event of the mapping is declared in the events configuration.
+pub fn validate_mappings(
+    configuration: &EventsConfiguration,
+    mappings: &CategoriesMapping,
+) -> Result<()> {
+    let mut mappings: Vec<_> = mappings.iter().collect();
+    mappings.sort_by_key(|(category_id, _)| *category_id);
+
+    for (category_id, events_mapping) in mappings {
+        let category = configuration
+            .categories()
+            .get(category_id)
+            .ok_or_else(|| UnknownCategory(category_id.clone()))?;
+
+        let mut event_ids: Vec<&String> = events_mapping.keys().collect();
+        event_ids.sort();
+        if let Some(event_id) = event_ids
+            .into_iter()
+            .find(|event_id| !category.events().contains_key(*event_id))
+        {
+            return Err(UnknownEvent {
+                category: category_id.clone(),
+                event: event_id.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     mod load_events_configuration {
         use crate::configuration::error::ConfigurationError;
-        use crate::configuration::events_mapping::{load_mappings, CategoriesMapping};
+        use crate::configuration::events_mapping::{load_mappings, CategoriesMapping, ColumnSelector};
         use crate::test_data::get_test_asset;
+        use std::num::NonZeroUsize;
 
         #[test]
         fn test() {
             let expected_result: CategoriesMapping = [
-                ("athletisme".to_string(), [("100m".to_string(), vec!["100m - All".to_string()]), ("stillstand".to_string(), vec![])].into_iter().collect()),
-                ("tout-terrain".to_string(), [("cross-country".to_string(), vec!["Cross court - All".to_string(), "Cross long - All".to_string()])].into_iter().collect()),
+                ("athletisme".to_string(), [("100m".to_string(), vec!["100m - All".into()]), ("stillstand".to_string(), vec![])].into_iter().collect()),
+                ("tout-terrain".to_string(), [("cross-country".to_string(), vec!["Cross court - All".into(), "Cross long - All".into()])].into_iter().collect()),
             ].into_iter().collect();
             let file = get_test_asset("configuration/2025.yml");
             let configuration = load_mappings(&file).unwrap();
@@ -41,5 +110,69 @@ mod test {
 
             assert!(matches!(error, ConfigurationError::Load(_)));
         }
+
+        #[test]
+        fn success_disambiguated() {
+            let expected_result: CategoriesMapping = [
+                ("athletisme".to_string(), [("parcours-iuf".to_string(), vec![ColumnSelector::Disambiguated {
+                    header: "Parcours IUF - All".to_string(),
+                    occurrence: None,
+                    after: Some("30m marcher sur la roue - All".to_string()),
+                }])].into_iter().collect()),
+                ("challenge-poussin".to_string(), [("challenge-poussin".to_string(), vec![
+                    "Lenteur avant (planche large) - All".into(),
+                    ColumnSelector::Disambiguated {
+                        header: "Parcours IUF - All".to_string(),
+                        occurrence: NonZeroUsize::new(1),
+                        after: None,
+                    },
+                ])].into_iter().collect()),
+            ].into_iter().collect();
+            let file = get_test_asset("configuration/2025-disambiguated.yml");
+            let configuration = load_mappings(&file).unwrap();
+
+            assert_eq!(expected_result, configuration);
+        }
     }
-}
\ No newline at end of file
+
+    mod validate_mappings {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::events_configuration::load_configuration;
+        use crate::configuration::events_mapping::{validate_mappings, CategoriesMapping};
+        use crate::test_data::get_test_asset;
+
+        #[test]
+        fn success() {
+            let configuration = load_configuration(&get_test_asset("configuration/events.yml")).unwrap();
+            let mappings: CategoriesMapping = [
+                ("artistique".to_string(), [("paire".to_string(), vec!["Paire - All".into()])].into_iter().collect()),
+            ].into_iter().collect();
+
+            validate_mappings(&configuration, &mappings).unwrap();
+        }
+
+        #[test]
+        fn fail_unknown_category() {
+            let configuration = load_configuration(&get_test_asset("configuration/events.yml")).unwrap();
+            let mappings: CategoriesMapping = [
+                ("tout-terrain".to_string(), [("cross-country".to_string(), vec!["Cross court - All".into()])].into_iter().collect()),
+            ].into_iter().collect();
+
+            let error = validate_mappings(&configuration, &mappings).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::UnknownCategory(category) if category == "tout-terrain"));
+        }
+
+        #[test]
+        fn fail_unknown_event() {
+            let configuration = load_configuration(&get_test_asset("configuration/events.yml")).unwrap();
+            let mappings: CategoriesMapping = [
+                ("athletisme".to_string(), [("400m".to_string(), vec!["400m - All".into()])].into_iter().collect()),
+            ].into_iter().collect();
+
+            let error = validate_mappings(&configuration, &mappings).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::UnknownEvent { category, event } if category == "athletisme" && event == "400m"));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Where each year's registration export and mapping lie.
+
+use crate::configuration::error::ConfigurationError;
+use crate::configuration::error::ConfigurationError::{DuplicateYear, InvalidYear, MissingMapping};
+use crate::configuration::error::Result;
+use chrono::NaiveDate;
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Every year of the archive, sorted by year.
+pub type HistoryManifest = BTreeMap<u16, YearEntry>;
+
+/// A year of the archive.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(rename_all = "kebab-case")]
+pub struct YearEntry {
+    /// Registration export of the year.
+    file: PathBuf,
+    /// Mapping of the year's columns to the canonical events.
+    mapping: PathBuf,
+    /// Start date of the convention, to compute the registrants' age instead of trusting the export.
+    start_date: Option<NaiveDate>,
+}
+
+impl YearEntry {
+    pub fn new(file: PathBuf, mapping: PathBuf, start_date: Option<NaiveDate>) -> Self {
+        Self {
+            file,
+            mapping,
+            start_date,
+        }
+    }
+}
+
+/// Load a manifest listing, for each year, its export, mapping, and optionally its start date:
+///
+/// ```yaml
+/// 2016:
+///   file: exports/2016.xls
+///   mapping: 2016.yml
+///   start-date: 2016-05-05
+/// ```
+///
+/// Relative paths are relative to the manifest's folder.
+pub fn load_manifest(path: &Path) -> Result<HistoryManifest> {
+    let settings = config::Config::builder()
+        .add_source(config::File::from(path))
+        .build()?;
+    let entries = settings.try_deserialize::<HashMap<String, YearEntry>>()?;
+
+    let folder = path.parent().unwrap_or(Path::new("."));
+    entries
+        .into_iter()
+        .map(|(year, entry)| {
+            let year = year.parse::<u16>().map_err(|_| InvalidYear(year))?;
+            let entry = YearEntry::new(folder.join(entry.file), folder.join(entry.mapping), entry.start_date);
+            Ok((year, entry))
+        })
+        .collect()
+}
+
+/// Build a manifest from a folder of exports named after their year (`2016.xls`, `2017.csv`...),
+/// whose mappings are the `<year>.yml` files of the mappings folder.
+/// Files not named after a year are ignored, and a year may have a single export.
+pub fn scan_directory(folder: &Path, mappings_folder: &Path) -> Result<HistoryManifest> {
+    let entries = folder.read_dir().map_err(|source| ConfigurationError::Io {
+        path: folder.display().to_string(),
+        source,
+    })?;
+
+    let mut paths: Vec<PathBuf> = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect();
+    paths.sort();
+
+    let mut manifest = HistoryManifest::new();
+    for path in paths {
+        let Some(year) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        if path.extension().is_some_and(|extension| extension == "yml") {
+            continue;
+        }
+
+        let mapping = mappings_folder.join(format!("{year}.yml"));
+        if !mapping.is_file() {
+            return Err(MissingMapping(year));
+        }
+        if let Some(entry) = manifest.get(&year) {
+            return Err(DuplicateYear {
+                year,
+                first: entry.file.display().to_string(),
+                second: path.display().to_string(),
+            });
+        }
+        manifest.insert(year, YearEntry::new(path, mapping, None));
+    }
+
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    mod load_manifest {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::history_manifest::load_manifest;
+        use crate::test_data::get_test_asset;
+        use chrono::NaiveDate;
+
+        #[test]
+        fn success() {
+            let manifest = load_manifest(&get_test_asset("history/manifest.yml")).unwrap();
+
+            assert_eq!(vec![&2016, &2017], manifest.keys().collect::<Vec<_>>());
+            assert_eq!(&get_test_asset("history/exports/2016.csv"), manifest[&2016].file());
+            assert_eq!(&get_test_asset("history/mappings/2017.yml"), manifest[&2017].mapping());
+            assert_eq!(&None, manifest[&2016].start_date());
+            assert_eq!(&NaiveDate::from_ymd_opt(2017, 5, 25), manifest[&2017].start_date());
+        }
+
+        #[test]
+        fn fail_invalid_year() {
+            let error = load_manifest(&get_test_asset("history/manifest-wrong-year.yml")).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::InvalidYear(year) if year == "last-year"));
+        }
+    }
+
+    mod scan_directory {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::history_manifest::scan_directory;
+        use crate::test_data::get_test_asset;
+        use std::env::temp_dir;
+        use std::fs::{create_dir_all, write};
+
+        #[test]
+        fn success() {
+            let manifest = scan_directory(&get_test_asset("history/exports"), &get_test_asset("history/mappings")).unwrap();
+
+            assert_eq!(vec![&2016, &2017], manifest.keys().collect::<Vec<_>>());
+            assert_eq!(&get_test_asset("history/exports/2017.csv"), manifest[&2017].file());
+            assert_eq!(&get_test_asset("history/mappings/2016.yml"), manifest[&2016].mapping());
+        }
+
+        #[test]
+        fn fail_missing_mapping() {
+            let error = scan_directory(&get_test_asset("history/exports"), &get_test_asset("configuration")).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::MissingMapping(2016)));
+        }
+
+        #[test]
+        fn fail_duplicate_year() {
+            let folder = temp_dir().join("history-duplicate-year");
+            create_dir_all(&folder).unwrap();
+            write(folder.join("2016.csv"), "").unwrap();
+            write(folder.join("2016.xls"), "").unwrap();
+
+            let error = scan_directory(&folder, &get_test_asset("history/mappings")).unwrap_err();
+
+            assert!(matches!(
+                error,
+                ConfigurationError::DuplicateYear { year: 2016, first, second }
+                    if first.ends_with("2016.csv") && second.ends_with("2016.xls")
+            ));
+        }
+    }
+}
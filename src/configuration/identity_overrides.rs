@@ -0,0 +1,105 @@
+//! Corrections to the matching of riders across years.
+
+use crate::configuration::error::ConfigurationError::InvalidRegistrationReference;
+use crate::configuration::error::Result;
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A registration of a given year, written `<year>/<registrant id>` in the overrides file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Getters)]
+pub struct RegistrationReference {
+    year: u16,
+    id: u16,
+}
+
+impl RegistrationReference {
+    pub fn new(year: u16, id: u16) -> Self {
+        Self { year, id }
+    }
+}
+
+impl FromStr for RegistrationReference {
+    type Err = crate::configuration::error::ConfigurationError;
+
+    fn from_str(reference: &str) -> Result<Self> {
+        reference
+            .split_once('/')
+            .and_then(|(year, id)| Some(Self::new(year.trim().parse().ok()?, id.trim().parse().ok()?)))
+            .ok_or_else(|| InvalidRegistrationReference(reference.to_string()))
+    }
+}
+
+/// Registrations known to be the same rider, or known to be different riders,
+/// whatever the automatic matching would decide.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Getters)]
+pub struct IdentityOverrides {
+    same: Vec<Vec<RegistrationReference>>,
+    different: Vec<Vec<RegistrationReference>>,
+}
+
+impl IdentityOverrides {
+    pub fn new(same: Vec<Vec<RegistrationReference>>, different: Vec<Vec<RegistrationReference>>) -> Self {
+        Self { same, different }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct IdentityOverridesFile {
+    same: Vec<Vec<String>>,
+    different: Vec<Vec<String>>,
+}
+
+/// Load the overrides file, made of groups of registrations:
+///
+/// ```yaml
+/// same:
+///   - ["2016/12", "2017/4"]
+/// different:
+///   - ["2016/3", "2017/3"]
+/// ```
+pub fn load_identity_overrides(path: &Path) -> Result<IdentityOverrides> {
+    let settings = config::Config::builder()
+        .add_source(config::File::from(path))
+        .build()?;
+    let file = settings.try_deserialize::<IdentityOverridesFile>()?;
+
+    let parse_groups = |groups: Vec<Vec<String>>| {
+        groups
+            .iter()
+            .map(|group| group.iter().map(|reference| reference.parse()).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()
+    };
+    Ok(IdentityOverrides::new(parse_groups(file.same)?, parse_groups(file.different)?))
+}
+
+#[cfg(test)]
+mod tests {
+    mod load_identity_overrides {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::identity_overrides::{load_identity_overrides, IdentityOverrides, RegistrationReference};
+        use crate::test_data::get_test_asset;
+
+        #[test]
+        fn success() {
+            let overrides = load_identity_overrides(&get_test_asset("history/identity-overrides.yml")).unwrap();
+
+            assert_eq!(
+                IdentityOverrides::new(
+                    vec![vec![RegistrationReference::new(2016, 3), RegistrationReference::new(2017, 103)]],
+                    vec![vec![RegistrationReference::new(2016, 4), RegistrationReference::new(2017, 104)]],
+                ),
+                overrides
+            );
+        }
+
+        #[test]
+        fn fail_invalid_reference() {
+            let error = load_identity_overrides(&get_test_asset("history/identity-overrides-wrong-reference.yml")).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::InvalidRegistrationReference(reference) if reference == "2016-3"));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::configuration::error::Result;
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::path::Path;
+
+/// How registration exports are laid out.
+/// Anything left out of the configuration file falls back to the registration platform's format.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters, Default)]
+#[serde(default)]
+pub struct ImportConfiguration {
+    columns: ColumnAliases,
+    genders: GenderLabels,
+    registrations: RegistrationValues,
+}
+
+/// Accepted headers for each identity column. Headers are compared regardless of case.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ColumnAliases {
+    id: Vec<String>,
+    first_name: Vec<String>,
+    last_name: Vec<String>,
+    birthday: Vec<String>,
+    age: Vec<String>,
+    gender: Vec<String>,
+    club: Vec<String>,
+}
+
+impl Default for ColumnAliases {
+    fn default() -> Self {
+        let aliases = |aliases: &[&str]| aliases.iter().map(|alias| alias.to_string()).collect();
+        Self {
+            id: aliases(&["Id", "Identifiant"]),
+            first_name: aliases(&["First Name", "Prénom"]),
+            last_name: aliases(&["Last Name", "Nom"]),
+            birthday: aliases(&["Birthday", "Date de naissance"]),
+            age: aliases(&["Age", "Âge"]),
+            gender: aliases(&["Gender", "Sexe", "Genre"]),
+            club: aliases(&["Club"]),
+        }
+    }
+}
+
+/// Accepted labels for each gender. Labels are compared regardless of case.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct GenderLabels {
+    male: Vec<String>,
+    female: Vec<String>,
+    non_binary: Vec<String>,
+    unspecified: Vec<String>,
+}
+
+impl Default for GenderLabels {
+    fn default() -> Self {
+        let labels = |labels: &[&str]| labels.iter().map(|label| label.to_string()).collect();
+        Self {
+            male: labels(&["Male", "Homme", "H", "M"]),
+            female: labels(&["Female", "Femme", "F"]),
+            non_binary: labels(&["Non-binary", "Non binaire", "Non-binaire", "Autre"]),
+            unspecified: labels(&["Not specified", "Non précisé", "Non renseigné"]),
+        }
+    }
+}
+
+/// Values of an event's cell telling whether the registrant is registered to it, compared regardless of case.
+/// Empty cells and booleans are always understood.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct RegistrationValues {
+    registered: Vec<String>,
+    not_registered: Vec<String>,
+}
+
+impl Default for RegistrationValues {
+    fn default() -> Self {
+        let values = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+        Self {
+            registered: values(&["VRAI", "TRUE", "Oui", "Yes", "X", "1"]),
+            not_registered: values(&["FAUX", "FALSE", "Non", "No", "0"]),
+        }
+    }
+}
+
+pub fn load_import_configuration(path: &Path) -> Result<ImportConfiguration> {
+    let settings = config::Config::builder()
+        .add_source(config::File::from(path))
+        .build()?;
+
+    Ok(settings.try_deserialize::<ImportConfiguration>()?)
+}
+
+#[cfg(test)]
+mod test {
+    mod load_import_configuration {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::import_configuration::{load_import_configuration, ColumnAliases, GenderLabels, ImportConfiguration, RegistrationValues};
+        use crate::test_data::get_test_asset;
+        use std::path::PathBuf;
+
+        #[test]
+        fn success() {
+            let configuration = load_import_configuration(&get_test_asset("configuration/import.yml")).unwrap();
+
+            assert_eq!(&vec!["Prénom".to_string(), "Firstname".to_string()], configuration.columns().first_name());
+            assert_eq!(ColumnAliases::default().last_name(), configuration.columns().last_name());
+            assert_eq!(&vec!["Garçon".to_string()], configuration.genders().male());
+            assert_eq!(GenderLabels::default().female(), configuration.genders().female());
+            assert_eq!(&vec!["Inscrit".to_string()], configuration.registrations().registered());
+            assert_eq!(RegistrationValues::default().not_registered(), configuration.registrations().not_registered());
+        }
+
+        #[test]
+        fn success_default_matches_repository_file() {
+            let file = PathBuf::from(format!("{}/configuration/import.yml", env!("CARGO_MANIFEST_DIR")));
+            let configuration = load_import_configuration(&file).unwrap();
+
+            assert_eq!(ImportConfiguration::default(), configuration);
+        }
+
+        #[test]
+        fn fail_wrong_format() {
+            let file = get_test_asset("configuration/import-wrong-format.yml");
+            let error = load_import_configuration(&file).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::Load(_)));
+        }
+    }
+}
@@ -1,4 +1,8 @@
-mod events_mapping;
-mod events_configuration;
-mod error;
-
+pub mod events_mapping;
+pub mod events_configuration;
+pub mod import_configuration;
+pub mod error;
+pub mod history_manifest;
+pub mod identity_overrides;
+pub mod programme;
+pub mod planning;
@@ -0,0 +1,154 @@
+//! Time slots available to the events, before they are scheduled.
+
+use crate::configuration::error::ConfigurationError::InvalidAvailableSlot;
+use crate::configuration::error::Result;
+use crate::configuration::programme::TransferTime;
+use chrono::{NaiveDate, NaiveTime};
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(rename_all = "kebab-case")]
+pub struct Planning {
+    /// Each slot hosts a single event.
+    slots: Vec<AvailableSlot>,
+    /// Events to schedule, by canonical id. Every event of the convention when empty.
+    #[serde(default)]
+    events: Vec<String>,
+    /// Slots some events are bound to, e.g. the marathon on Sunday morning.
+    #[serde(default)]
+    constraints: Vec<Constraint>,
+    /// See [crate::configuration::programme::Programme::transfer_times].
+    #[serde(default)]
+    transfer_times: Vec<TransferTime>,
+    /// See [crate::configuration::programme::Programme::min_break].
+    #[serde(default)]
+    min_break: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+pub struct AvailableSlot {
+    day: NaiveDate,
+    start: NaiveTime,
+    end: NaiveTime,
+    venue: String,
+}
+
+/// Where and when an event may take place. Fields left out do not restrict the slots.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(rename_all = "kebab-case")]
+pub struct Constraint {
+    event: String,
+    day: Option<NaiveDate>,
+    venue: Option<String>,
+    /// The slot starts at this time or later.
+    not_before: Option<NaiveTime>,
+    /// The slot ends at this time or earlier.
+    not_after: Option<NaiveTime>,
+}
+
+impl Constraint {
+    pub fn allows(&self, slot: &AvailableSlot) -> bool {
+        self.day.is_none_or(|day| day == slot.day)
+            && self.venue.as_ref().is_none_or(|venue| *venue == slot.venue)
+            && self.not_before.is_none_or(|not_before| slot.start >= not_before)
+            && self.not_after.is_none_or(|not_after| slot.end <= not_after)
+    }
+}
+
+/// E.g. `2016-05-05 09:00-10:30 (Stade)`.
+impl Display for AvailableSlot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}-{} ({})",
+            self.day,
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M"),
+            self.venue
+        )
+    }
+}
+
+/// Load a planning such as:
+///
+/// ```yaml
+/// slots:
+///   - day: 2016-05-08
+///     start: "09:00"
+///     end: "12:00"
+///     venue: Route
+///   - day: 2016-05-08
+///     start: "14:00"
+///     end: "16:00"
+///     venue: Gymnase
+/// events: [courses-sur-route/marathon-illimite, urbain/flat]
+/// constraints:
+///   - event: courses-sur-route/marathon-illimite
+///     day: 2016-05-08
+///     not-after: "12:00"
+/// transfer-times:
+///   - venues: [Route, Gymnase]
+///     minutes: 15
+/// min-break: 5
+/// ```
+///
+/// Times are quoted, YAML reading `09:00` as a number otherwise. Every slot has to end after it starts.
+pub fn load_planning(path: &Path) -> Result<Planning> {
+    let settings = config::Config::builder()
+        .add_source(config::File::from(path))
+        .build()?;
+    let planning = settings.try_deserialize::<Planning>()?;
+
+    match planning.slots.iter().find(|slot| slot.end <= slot.start) {
+        Some(slot) => Err(InvalidAvailableSlot(slot.to_string())),
+        None => Ok(planning),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod load_planning {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::planning::load_planning;
+        use crate::test_data::get_test_asset;
+        use chrono::NaiveDate;
+
+        #[test]
+        fn success() {
+            let planning = load_planning(&get_test_asset("configuration/planning.yml")).unwrap();
+
+            assert_eq!(6, planning.slots().len());
+            assert_eq!(5, planning.events().len());
+            let constraint = &planning.constraints()[0];
+            assert_eq!("urbain/street", constraint.event());
+            assert_eq!(NaiveDate::from_ymd_opt(2016, 5, 6), *constraint.day());
+            assert_eq!(None, *constraint.venue());
+            assert_eq!(5, *planning.min_break());
+        }
+
+        #[test]
+        fn fail_slot_ending_before_start() {
+            let error = load_planning(&get_test_asset("configuration/planning-wrong-slot.yml")).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::InvalidAvailableSlot(slot) if slot == "2016-05-05 11:00-10:00 (Gymnase)"));
+        }
+    }
+
+    mod allows {
+        use crate::configuration::planning::load_planning;
+        use crate::test_data::get_test_asset;
+
+        #[test]
+        fn success() {
+            let planning = load_planning(&get_test_asset("configuration/planning.yml")).unwrap();
+            let constraint = &planning.constraints()[0];
+
+            let allowed: Vec<bool> = planning.slots().iter().map(|slot| constraint.allows(slot)).collect();
+
+            assert_eq!(vec![false, false, false, false, true, false], allowed);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! When and where each event takes place.
+
+use crate::configuration::error::ConfigurationError::InvalidSlot;
+use crate::configuration::error::Result;
+use chrono::{NaiveDate, NaiveTime};
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+#[serde(rename_all = "kebab-case")]
+pub struct Programme {
+    /// An event may take place several times, e.g. heats and a final.
+    slots: Vec<Slot>,
+    /// Minutes needed to go from a venue to another one. Venues not listed are next to each other.
+    #[serde(default)]
+    transfer_times: Vec<TransferTime>,
+    /// Minutes a rider needs between two events, whatever their venues, e.g. to change unicycle.
+    #[serde(default)]
+    min_break: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+pub struct Slot {
+    /// Canonical id of the event, e.g. `athletisme/100m`, see [crate::registration::convention::Convention::normalize].
+    event: String,
+    day: NaiveDate,
+    start: NaiveTime,
+    end: NaiveTime,
+    venue: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Getters)]
+pub struct TransferTime {
+    /// Both venues, in any order.
+    venues: (String, String),
+    minutes: u32,
+}
+
+impl Programme {
+    pub fn new(slots: Vec<Slot>, transfer_times: Vec<TransferTime>, min_break: u32) -> Self {
+        Self {
+            slots,
+            transfer_times,
+            min_break,
+        }
+    }
+
+    /// Minutes a rider needs between the end of an event in a venue and the start of another one in another venue.
+    pub fn required_break(&self, from: &str, to: &str) -> u32 {
+        let transfer_time = self
+            .transfer_times
+            .iter()
+            .find(|transfer| {
+                let (first, second) = &transfer.venues;
+                (first == from && second == to) || (first == to && second == from)
+            })
+            .filter(|_| from != to)
+            .map(|transfer| transfer.minutes)
+            .unwrap_or_default();
+
+        self.min_break + transfer_time
+    }
+}
+
+impl Slot {
+    pub fn new(event: String, day: NaiveDate, start: NaiveTime, end: NaiveTime, venue: String) -> Self {
+        Self {
+            event,
+            day,
+            start,
+            end,
+            venue,
+        }
+    }
+}
+
+impl TransferTime {
+    #[cfg(test)]
+    pub fn new(venues: (String, String), minutes: u32) -> Self {
+        Self { venues, minutes }
+    }
+}
+
+/// Load a programme such as:
+///
+/// ```yaml
+/// slots:
+///   - event: athletisme/100m
+///     day: 2016-05-05
+///     start: "09:00"
+///     end: "10:30"
+///     venue: Stade
+/// transfer-times:
+///   - venues: [Stade, Gymnase]
+///     minutes: 15
+/// min-break: 5
+/// ```
+///
+/// Times are quoted, YAML reading `09:00` as a number otherwise. Every slot has to end after it starts.
+pub fn load_programme(path: &Path) -> Result<Programme> {
+    let settings = config::Config::builder()
+        .add_source(config::File::from(path))
+        .build()?;
+    let programme = settings.try_deserialize::<Programme>()?;
+
+    match programme.slots.iter().find(|slot| slot.end <= slot.start) {
+        Some(slot) => Err(InvalidSlot(slot.event.clone())),
+        None => Ok(programme),
+    }
+}
+
+/// Export the programme in the format read by [load_programme].
+pub fn generate_programme_file(programme: &Programme) -> String {
+    let mut content = "slots:".to_string();
+    for slot in &programme.slots {
+        content = format!(
+            "{content}\n  - event: {}\n    day: {}\n    start: \"{}\"\n    end: \"{}\"\n    venue: {}",
+            quote(&slot.event),
+            slot.day,
+            slot.start.format("%H:%M"),
+            slot.end.format("%H:%M"),
+            quote(&slot.venue)
+        );
+    }
+    if programme.slots.is_empty() {
+        content.push_str(" []");
+    }
+    if !programme.transfer_times.is_empty() {
+        content.push_str("\ntransfer-times:");
+        for transfer in &programme.transfer_times {
+            let (first, second) = &transfer.venues;
+            content = format!(
+                "{content}\n  - venues: [{}, {}]\n    minutes: {}",
+                quote(first),
+                quote(second),
+                transfer.minutes
+            );
+        }
+    }
+
+    format!("{content}\nmin-break: {}\n", programme.min_break)
+}
+
+/// Names may hold characters meaningful to YAML, such as `:` or `"`.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    mod load_programme {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::programme::load_programme;
+        use crate::test_data::get_test_asset;
+        use chrono::NaiveTime;
+
+        #[test]
+        fn success() {
+            let programme = load_programme(&get_test_asset("configuration/programme.yml")).unwrap();
+
+            assert_eq!(5, programme.slots().len());
+            let slot = &programme.slots()[0];
+            assert_eq!("athletisme/100m", slot.event());
+            assert_eq!(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), *slot.start());
+            assert_eq!("Stade", slot.venue());
+            assert_eq!(5, *programme.min_break());
+        }
+
+        #[test]
+        fn fail_slot_ending_before_start() {
+            let error = load_programme(&get_test_asset("configuration/programme-wrong-slot.yml")).unwrap_err();
+
+            assert!(matches!(error, ConfigurationError::InvalidSlot(event) if event == "urbain/trial"));
+        }
+    }
+
+    mod generate_programme_file {
+        use crate::configuration::programme::{generate_programme_file, load_programme, Programme, Slot};
+        use crate::test_data::get_test_asset;
+        use chrono::{NaiveDate, NaiveTime};
+        use std::env::temp_dir;
+        use std::fs::write;
+
+        #[test]
+        fn success() {
+            let programme = load_programme(&get_test_asset("configuration/programme.yml")).unwrap();
+            let file = temp_dir().join("generated-programme.yml");
+
+            write(&file, generate_programme_file(&programme)).unwrap();
+
+            assert_eq!(programme, load_programme(&file).unwrap());
+        }
+
+        #[test]
+        fn success_special_characters() {
+            let time = |hour: u32| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+            let slot = Slot::new(
+                "10 kilomètres - Standard 24\"".to_string(),
+                NaiveDate::from_ymd_opt(2016, 5, 5).unwrap(),
+                time(9),
+                time(10),
+                "Route: départ".to_string(),
+            );
+            let programme = Programme::new(vec![slot], vec![], 0);
+            let file = temp_dir().join("generated-programme-special-characters.yml");
+
+            write(&file, generate_programme_file(&programme)).unwrap();
+
+            assert_eq!(programme, load_programme(&file).unwrap());
+        }
+    }
+
+    mod required_break {
+        use crate::configuration::programme::{Programme, TransferTime};
+
+        #[test]
+        fn success() {
+            let programme = Programme::new(vec![], vec![TransferTime::new(("Stade".to_string(), "Gymnase".to_string()), 15)], 5);
+
+            assert_eq!(20, programme.required_break("Gymnase", "Stade"));
+            assert_eq!(5, programme.required_break("Stade", "Stade"));
+            assert_eq!(5, programme.required_break("Stade", "Parking"));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::configuration::error::ConfigurationError;
+use crate::registration::diagnostic::Diagnostic;
 use crate::statistics::error::DrawingError;
 use thiserror::Error;
 
@@ -6,15 +8,46 @@ pub type Result<T, E = ApplicationError> = std::result::Result<T, E>;
 #[derive(Debug, Error)]
 pub enum ApplicationError {
     #[error(transparent)]
-    Sheet(#[from] calamine::XlsError),
+    Sheet(#[from] calamine::Error),
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
     #[error(transparent)]
     Deserialization(#[from] calamine::DeError),
+    #[error("The file has no sheet.")]
+    NoSheet,
     #[error("The file has no header row.")]
     NoHeaders,
+    #[error("No column found for the {field} (expected one of `{aliases}`).")]
+    MissingColumn { field: String, aliases: String },
     #[error("The row is misformatted.")]
     MisformattedRow,
+    #[error("{0}")]
+    InvalidCell(Box<Diagnostic>),
+    #[error("{0} cell(s) of the registrations are invalid.")]
+    InvalidRegistrations(usize),
     #[error("A cell has a wrong format: {0}")]
     WrongFormat(String),
+    #[error("The column `{0}` is not mapped to any event.")]
+    UnmappedEvent(String),
+    #[error("No event or category is named `{0}`.")]
+    UnknownEvent(String),
+    #[error("Several events are named `{0}`: normalize the convention with a mapping to refer to events by their canonical id.")]
+    AmbiguousEvent(String),
+    #[error("Several columns are named `{0}`: use `occurrence` or `after` in the mapping to designate one.")]
+    AmbiguousHeader(String),
+    #[error("The column `{column}` (event #{index}) is mapped to several events: {events}.")]
+    AmbiguousColumn {
+        column: String,
+        index: usize,
+        events: String,
+    },
     #[error(transparent)]
     Drawing(#[from] DrawingError),
+    #[error(transparent)]
+    Configuration(#[from] ConfigurationError),
+    #[error("Cannot access `{path}`: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
 }
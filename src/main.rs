@@ -1,19 +1,21 @@
-use std::fs::write;
-use std::path::PathBuf;
-use crate::statistics::events_registrants_dependency::generate_csv_file;
+use crate::cli::Cli;
+use clap::Parser;
+use std::process::ExitCode;
 
 pub mod registration;
+mod cli;
 mod error;
 mod statistics;
 mod configuration;
 #[cfg(test)]
 pub mod test_data;
 
-fn main() {
-    let csv_content = generate_csv_file(&PathBuf::from(format!(
-        "{}/test/assets/{}",
-        env!("CARGO_MANIFEST_DIR"),
-        "2024.xls"
-    )));
-    write("result.csv", &csv_content).unwrap();
+fn main() -> ExitCode {
+    match cli::run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            cli::exit_code(&error)
+        }
+    }
 }
@@ -0,0 +1,148 @@
+//! Where each piece of information lies in a registration export.
+
+use crate::configuration::import_configuration::ColumnAliases;
+use crate::error;
+use crate::error::ApplicationError::MissingColumn;
+use crate::registration::event::split_name;
+use crate::registration::team::DetailKind;
+use derive_getters::Getters;
+
+/// Position of the identity columns and of the events' columns, found from the header row.
+/// Extra columns (e-mail, phone...) are simply ignored.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct ColumnLayout {
+    id: usize,
+    first_name: usize,
+    last_name: usize,
+    birthday: usize,
+    age: usize,
+    gender: usize,
+    club: Option<usize>,
+    /// Column of each event, in the order of [crate::registration::event::Event::index].
+    events: Vec<usize>,
+    /// Column, event index and kind of each detail column (captain, team, group or partner name).
+    details: Vec<(usize, usize, DetailKind)>,
+}
+
+impl ColumnLayout {
+    pub fn from_headers(headers: &[String], aliases: &ColumnAliases) -> error::Result<Self> {
+        let events: Vec<usize> = headers
+            .iter()
+            .enumerate()
+            .filter(|(_, header)| is_event_header(header))
+            .map(|(column, _)| column)
+            .collect();
+        let details = headers
+            .iter()
+            .enumerate()
+            .filter_map(|(column, header)| {
+                let (discipline, kind) = DetailKind::from_header(header)?;
+                let event = find_detailed_event(headers, &events, column, discipline)?;
+                Some((column, event, kind))
+            })
+            .collect();
+
+        Ok(Self {
+            id: require_column(headers, "id", aliases.id())?,
+            first_name: require_column(headers, "first name", aliases.first_name())?,
+            last_name: require_column(headers, "last name", aliases.last_name())?,
+            birthday: require_column(headers, "birthday", aliases.birthday())?,
+            age: require_column(headers, "age", aliases.age())?,
+            gender: require_column(headers, "gender", aliases.gender())?,
+            club: find_column(headers, aliases.club()),
+            events,
+            details,
+        })
+    }
+}
+
+/// Events are denoted by having ` - ` in their names.
+/// Columns detailing a registration (captain, team, group or partner names) are not events by themselves.
+pub fn is_event_header(header: &str) -> bool {
+    header.contains(" - ") && DetailKind::from_header(header).is_none()
+}
+
+/// Index of the event a detail column is about: the closest event of the same discipline before it,
+/// or the first one after it. Detail columns of a discipline without event are ignored.
+fn find_detailed_event(headers: &[String], events: &[usize], column: usize, discipline: &str) -> Option<usize> {
+    let same_discipline = |event: &&usize| split_name(&headers[**event]).0 == discipline;
+    let preceding = events.iter().rposition(|event| *event < column && same_discipline(&event));
+    preceding.or_else(|| events.iter().position(|event| same_discipline(&event)))
+}
+
+fn find_column(headers: &[String], aliases: &[String]) -> Option<usize> {
+    headers.iter().position(|header| {
+        let header = header.trim().to_lowercase();
+        aliases.iter().any(|alias| alias.to_lowercase() == header)
+    })
+}
+
+fn require_column(headers: &[String], field: &str, aliases: &[String]) -> error::Result<usize> {
+    find_column(headers, aliases).ok_or_else(|| MissingColumn {
+        field: field.to_string(),
+        aliases: aliases.join("`, `"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    mod from_headers {
+        use crate::configuration::import_configuration::ColumnAliases;
+        use crate::error::ApplicationError;
+        use crate::registration::column_layout::ColumnLayout;
+        use crate::registration::team::DetailKind;
+
+        fn headers(headers: &[&str]) -> Vec<String> {
+            headers.iter().map(|header| header.to_string()).collect()
+        }
+
+        #[test]
+        fn success() {
+            let headers = headers(&["Id", "First Name", "Last Name", "Birthday", "Age", "Gender", "Club", "Basket A - All", "Basket A - Nom de l'équipe", "Cross long - All"]);
+
+            let layout = ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap();
+
+            assert_eq!((0, 1, 2, 3, 4, 5, Some(6)), (layout.id, layout.first_name, layout.last_name, layout.birthday, layout.age, layout.gender, layout.club));
+            assert_eq!(vec![7, 9], layout.events);
+            assert_eq!(vec![(8, 0, DetailKind::Team)], layout.details);
+        }
+
+        #[test]
+        fn success_details_of_duplicate_disciplines() {
+            let headers = headers(&["Id", "First Name", "Last Name", "Birthday", "Age", "Gender", "Paire - Nom du partenaire", "Paire - All", "Hockey - All", "Paire - All", "Paire - Nom du partenaire", "Groupe - Nom du groupe"]);
+
+            let layout = ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap();
+
+            assert_eq!(vec![7, 8, 9], layout.events);
+            assert_eq!(vec![(6, 0, DetailKind::Partner), (10, 2, DetailKind::Partner)], layout.details);
+        }
+
+        #[test]
+        fn success_reordered_french_headers() {
+            let headers = headers(&["E-mail", "Nom", "PRÉNOM", "Sexe", "Club", "Téléphone", "Date de naissance", "Âge", "Identifiant", "100m - All"]);
+
+            let layout = ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap();
+
+            assert_eq!((8, 2, 1, 6, 7, 3, Some(4)), (layout.id, layout.first_name, layout.last_name, layout.birthday, layout.age, layout.gender, layout.club));
+            assert_eq!(vec![9], layout.events);
+        }
+
+        #[test]
+        fn success_no_club() {
+            let headers = headers(&["Id", "First Name", "Last Name", "Birthday", "Age", "Gender"]);
+
+            let layout = ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap();
+
+            assert_eq!(None, layout.club);
+        }
+
+        #[test]
+        fn fail_missing_column() {
+            let headers = headers(&["Id", "First Name", "Birthday", "Age", "Gender"]);
+
+            let error = ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::MissingColumn { field, .. } if field == "last name"));
+        }
+    }
+}
@@ -1,11 +1,20 @@
+use crate::configuration::error::ConfigurationError::UnknownCategory;
+use crate::configuration::events_configuration::EventsConfiguration;
+use crate::configuration::events_mapping::{canonical_event_category, canonical_event_id, CategoriesMapping, ColumnSelector};
 use crate::error;
-use crate::error::ApplicationError::NoHeaders;
+use crate::error::ApplicationError::{AmbiguousColumn, AmbiguousHeader, InvalidCell, NoHeaders, UnmappedEvent};
+use crate::registration::column_layout::{is_event_header, ColumnLayout};
+use crate::registration::diagnostic::{Diagnostic, LoadingMode, Severity};
 use crate::registration::event::Event;
+use crate::registration::loading_options::LoadingOptions;
 use crate::registration::registrant;
 use crate::registration::registrant::Registrant;
-use calamine::{open_workbook, HeaderRow, Reader, Xls};
+use crate::registration::spreadsheet::read_range;
+use crate::registration::team;
+use crate::registration::team::{Team, TeamEntry};
 use derive_getters::Getters;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
 
 /// A convention is a wrapper over its registrants, and events they have registered to.
 ///
@@ -15,14 +24,38 @@ use std::path::PathBuf;
 pub struct Convention {
     /// Second member of this tuple directly refers to the events' index in [Convention::events].
     registrations: Vec<(Registrant, Vec<usize>)>,
+    /// Team, pair or group details of each registration, in the order of [Convention::registrations].
+    team_entries: Vec<Vec<TeamEntry>>,
     events: Vec<Event>,
     /// This directly use [Convention::events] indexes.
     participants_by_event: Vec<Vec<Registrant>>,
+    /// Where the registrations were loaded from, if loaded from an export.
+    source: Option<RegistrationSource>,
+}
+
+/// Location of the registrations in the export they were loaded from.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct RegistrationSource {
+    sheet: String,
+    /// Header of the registrants' id column.
+    id_column: String,
+    /// 1-based row of each registration, in the order of [Convention::registrations].
+    rows: Vec<u32>,
+}
+
+impl RegistrationSource {
+    pub fn new(sheet: String, id_column: String, rows: Vec<u32>) -> Self {
+        Self { sheet, id_column, rows }
+    }
 }
 
 impl Convention {
     /// Building a convention is done by associating each participant to the event it has registered to.
-    pub fn build(registrations: Vec<(Registrant, Vec<usize>)>, events: Vec<Event>) -> Self {
+    pub fn build(
+        registrations: Vec<(Registrant, Vec<usize>)>,
+        team_entries: Vec<Vec<TeamEntry>>,
+        events: Vec<Event>,
+    ) -> Self {
         let mut participants_by_event: Vec<_> = (0..events.len()).map(|_| vec![]).collect();
 
         for (registrant, registered_events) in registrations.clone() {
@@ -36,55 +69,323 @@ impl Convention {
 
         Convention {
             registrations,
+            team_entries,
             events,
             participants_by_event,
+            source: None,
+        }
+    }
+
+    pub fn with_source(mut self, source: Option<RegistrationSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Participants of each discipline, e.g. every 10 km rider whatever their wheel size.
+    /// A registrant entering several classes of a discipline is counted once.
+    pub fn participants_by_discipline(&self) -> BTreeMap<String, Vec<Registrant>> {
+        self.group_participants(|event| Some(event.discipline()))
+    }
+
+    /// Participants of each class, e.g. every Standard 24" rider across road races.
+    /// A registrant entering several events of a class is counted once.
+    /// Events without class, such as canonical events, are left out.
+    pub fn participants_by_class(&self) -> BTreeMap<String, Vec<Registrant>> {
+        self.group_participants(|event| event.class().as_ref())
+    }
+
+    fn group_participants(&self, key: impl Fn(&Event) -> Option<&String>) -> BTreeMap<String, Vec<Registrant>> {
+        let mut participants: BTreeMap<String, Vec<Registrant>> = BTreeMap::new();
+        for (registrant, registered_events) in &self.registrations {
+            let keys: BTreeSet<&String> = registered_events
+                .iter()
+                .filter_map(|event| key(&self.events[*event]))
+                .collect();
+            for key in keys {
+                participants.entry(key.clone()).or_default().push(registrant.clone());
+            }
+        }
+
+        participants
+    }
+
+    /// Teams, pairs or groups of an event, reconstructed from the details given by its participants.
+    /// See [team::build_teams] for how participants are gathered.
+    pub fn teams(&self, event: usize) -> Vec<Team> {
+        let participants: Vec<(&Registrant, Option<&TeamEntry>)> = self
+            .registrations
+            .iter()
+            .zip(&self.team_entries)
+            .filter(|((_, registered_events), _)| registered_events.contains(&event))
+            .map(|((registrant, _), entries)| (registrant, entries.iter().find(|entry| *entry.event() == event)))
+            .collect();
+
+        team::build_teams(&participants)
+    }
+
+    /// Build a new convention whose events are the canonical `category/event` events of the mapping,
+    /// instead of the raw spreadsheet columns.
+    ///
+    /// A canonical event gathers every column the mapping designates for it,
+    /// a registrant being counted once even if registered to several of these columns.
+    /// Canonical events without any column this year are left out.
+    ///
+    /// Each column has to be designated by exactly one canonical event:
+    /// a header matching several columns, or a column claimed by several events, is reported as ambiguous.
+    pub fn normalize(&self, mappings: &CategoriesMapping) -> error::Result<Convention> {
+        let mut columns_by_canonical_event: BTreeMap<String, BTreeSet<usize>> = BTreeMap::new();
+        for (category, events_mapping) in mappings {
+            for (event, selectors) in events_mapping {
+                let mut columns = BTreeSet::new();
+                for selector in selectors {
+                    columns.extend(resolve_columns(selector, &self.events)?);
+                }
+                if !columns.is_empty() {
+                    columns_by_canonical_event.insert(canonical_event_id(category, event), columns);
+                }
+            }
+        }
+
+        let mut canonical_events_by_column: Vec<Vec<usize>> = vec![vec![]; self.events.len()];
+        let mut events = vec![];
+        for (index, (id, columns)) in columns_by_canonical_event.into_iter().enumerate() {
+            for column in columns {
+                canonical_events_by_column[column].push(index);
+            }
+            events.push(Event::new(index, id));
+        }
+
+        for column in &self.events {
+            let canonical_events = &canonical_events_by_column[*column.index()];
+            match canonical_events.len() {
+                0 => return Err(UnmappedEvent(column.name().clone())),
+                1 => {}
+                _ => {
+                    return Err(AmbiguousColumn {
+                        column: column.name().clone(),
+                        index: *column.index(),
+                        events: canonical_events
+                            .iter()
+                            .map(|event| events[*event].name().as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    });
+                }
+            }
         }
+
+        let registrations = self
+            .registrations
+            .iter()
+            .map(|(registrant, columns)| {
+                let registered_events: BTreeSet<usize> = columns
+                    .iter()
+                    .flat_map(|column| canonical_events_by_column[*column].iter().copied())
+                    .collect();
+                (registrant.clone(), registered_events.into_iter().collect())
+            })
+            .collect();
+
+        let team_entries = self
+            .team_entries
+            .iter()
+            .map(|entries| {
+                let mut normalized_entries: Vec<TeamEntry> = vec![];
+                for entry in entries {
+                    let event = canonical_events_by_column[*entry.event()][0];
+                    // Several columns of a canonical event may be detailed: the first details are kept
+                    if !normalized_entries.iter().any(|normalized| *normalized.event() == event) {
+                        normalized_entries.push(entry.clone().with_event(event));
+                    }
+                }
+                normalized_entries.sort_by_key(|entry| *entry.event());
+                normalized_entries
+            })
+            .collect();
+
+        Ok(Convention::build(registrations, team_entries, events).with_source(self.source.clone()))
+    }
+
+    /// Build a new convention whose events are the categories of the events configuration,
+    /// named after their display name, e.g. `Athlétisme` or `Urbain`.
+    ///
+    /// The convention has to be normalized first, every canonical event belonging to a category of the configuration.
+    /// A registrant entering several events of a category is counted once.
+    /// Categories without any event this year are left out, and teams are not kept.
+    pub fn aggregate_by_category(&self, configuration: &EventsConfiguration) -> error::Result<Convention> {
+        let mut category_by_event: Vec<&str> = vec![];
+        for event in &self.events {
+            let category = canonical_event_category(event.name()).unwrap_or(event.name());
+            if !configuration.categories().contains_key(category) {
+                return Err(UnknownCategory(category.to_string()).into());
+            }
+            category_by_event.push(category);
+        }
+
+        let categories: Vec<&str> = category_by_event.iter().copied().collect::<BTreeSet<_>>().into_iter().collect();
+        let events = categories
+            .iter()
+            .enumerate()
+            .map(|(index, category)| Event::new(index, configuration.categories()[*category].name().clone()))
+            .collect();
+
+        let registrations = self
+            .registrations
+            .iter()
+            .map(|(registrant, registered_events)| {
+                let registered_categories: BTreeSet<usize> = registered_events
+                    .iter()
+                    .filter_map(|event| categories.binary_search(&category_by_event[*event]).ok())
+                    .collect();
+                (registrant.clone(), registered_categories.into_iter().collect())
+            })
+            .collect();
+
+        Ok(Convention::build(registrations, vec![vec![]; self.registrations.len()], events)
+            .with_source(self.source.clone()))
     }
 
     #[cfg(test)]
     pub fn new(
         registrations: Vec<(Registrant, Vec<usize>)>,
+        team_entries: Vec<Vec<TeamEntry>>,
         events: Vec<Event>,
         participants_by_event: Vec<Vec<Registrant>>,
     ) -> Self {
         Self {
             registrations,
+            team_entries,
             events,
             participants_by_event,
+            source: None,
         }
     }
 }
 
-/// Load a convention into memory from an XLS file.
-/// It is expected for the XLS file to have the following columns:
+/// Load a convention into memory from a registration export (XLS, XLSX, XLSB, ODS or CSV).
+/// It is expected for the export to have the following columns, found by their header in any order
+/// (see [crate::configuration::import_configuration::ColumnAliases] for the accepted headers):
 /// - Id: Integer
 /// - First Name: String
 /// - Last Name: String
-/// - Birthday: String (mm.dd.YYYY)
-/// - Age: Integer
-/// - Gender: `Male` or `Female`
-/// - Club: String
+/// - Birthday: Date, or String (dd.mm.YYYY or YYYY-mm-dd)
+/// - Age: Integer, superseded by the age at [LoadingOptions::start_date] when known
+/// - Gender: a label of [crate::configuration::import_configuration::GenderLabels], e.g. `Male` or `Femme`
+/// - Club: String, optional
+///
+/// Along with a list of events, whose cell's values could be nothing, a boolean,
+/// or one of the [crate::configuration::import_configuration::RegistrationValues] such as `VRAI`.
 ///
-/// Followed by a list of events, whose cell's values could be nothing or `VRAI` or a `true` boolean.
-#[allow(dead_code)]
-pub fn load_convention(path: &PathBuf) -> error::Result<Convention> {
-    let mut workbook: Xls<_> = open_workbook(path)?;
-    let range = workbook
-        .with_header_row(HeaderRow::FirstNonEmptyRow)
-        .worksheet_range("Worksheet1")?;
-
-    let headers = range.headers();
-    let events = retrieve_event_list(headers)?;
+/// In [LoadingMode::Strict], the first malformed row makes the loading fail.
+/// In [LoadingMode::Lenient], malformed rows are left out and returned as diagnostics.
+/// Either way, unknown registration values and ages disagreeing with the start date are returned as warnings.
+pub fn load_convention(
+    path: &Path,
+    options: &LoadingOptions,
+) -> error::Result<(Convention, Vec<Diagnostic>)> {
+    let (sheet, range) = read_range(path, options.sheet())?;
+
+    let headers = range.headers().ok_or(NoHeaders)?;
+    let layout = ColumnLayout::from_headers(&headers, options.import().columns())?;
+    let first_row = range.start().map(|(row, _)| row).unwrap_or_default();
     let mut registrations = vec![];
+    let mut team_entries = vec![];
+    let mut rows = vec![];
+    let mut diagnostics = vec![];
 
-    for row in range.rows().skip(1) {
+    for (index, row) in range.rows().enumerate().skip(1) {
         // Skipping the header line
-        let (registrant, registered_events) = registrant::parse_row(row)?;
-        registrations.push((registrant, registered_events));
+        let diagnostic = |severity: Severity, column: usize, reason: String| {
+            Diagnostic::new(
+                severity,
+                sheet.clone(),
+                first_row + index as u32 + 1,
+                headers.get(column).cloned().unwrap_or_default(),
+                row.get(column).map(|cell| cell.to_string()).unwrap_or_default(),
+                reason,
+            )
+        };
+
+        match registrant::parse_row(row, &layout, options.import()) {
+            Ok((mut registrant, registered_events, warnings)) => {
+                diagnostics.extend(warnings.into_iter().map(|warning| {
+                    diagnostic(Severity::Warning, *warning.column(), warning.error().to_string())
+                }));
+                if let Some(start_date) = options.start_date() {
+                    let age = registrant.age_at(*start_date);
+                    if age != *registrant.age() {
+                        diagnostics.push(diagnostic(
+                            Severity::Warning,
+                            *layout.age(),
+                            format!("The registrant is {age} at the convention's start date ({start_date})."),
+                        ));
+                    }
+                    registrant = registrant.with_age(age);
+                }
+                registrations.push((registrant, registered_events));
+                team_entries.push(team::parse_team_entries(row, &layout));
+                rows.push(first_row + index as u32 + 1);
+            }
+            Err(errors) => {
+                let row_diagnostics = errors.into_iter().map(|error| {
+                    diagnostic(Severity::Error, *error.column(), error.error().to_string())
+                });
+                match options.mode() {
+                    LoadingMode::Strict => {
+                        let diagnostic = row_diagnostics.into_iter().next().expect("A row in error has at least one error");
+                        return Err(InvalidCell(Box::new(diagnostic)));
+                    }
+                    LoadingMode::Lenient => diagnostics.extend(row_diagnostics),
+                }
+            }
+        }
     }
 
-    let convention = Convention::build(registrations, events);
-    Ok(convention)
+    let source = RegistrationSource::new(sheet, headers[*layout.id()].clone(), rows);
+    let events = retrieve_event_list(Some(headers))?;
+    let convention = Convention::build(registrations, team_entries, events).with_source(Some(source));
+    Ok((convention, diagnostics))
+}
+
+/// Find the columns designated by the selector.
+/// A column missing from this year's export is not an error: the event may simply not have taken place.
+fn resolve_columns(selector: &ColumnSelector, columns: &[Event]) -> error::Result<Vec<usize>> {
+    match selector {
+        ColumnSelector::Header(header) => {
+            let matching: Vec<usize> = columns
+                .iter()
+                .filter(|column| column.name() == header)
+                .map(|column| *column.index())
+                .collect();
+            if matching.len() > 1 {
+                Err(AmbiguousHeader(header.clone()))
+            } else {
+                Ok(matching)
+            }
+        }
+        ColumnSelector::Disambiguated {
+            header,
+            occurrence,
+            after,
+        } => {
+            let start = match after {
+                Some(after) => match columns.iter().position(|column| column.name() == after) {
+                    Some(position) => position + 1,
+                    None => return Ok(vec![]),
+                },
+                None => 0,
+            };
+            let rank = occurrence.map(|occurrence| occurrence.get() - 1).unwrap_or(0);
+
+            Ok(columns
+                .iter()
+                .skip(start)
+                .filter(|column| column.name() == header)
+                .nth(rank)
+                .map(|column| vec![*column.index()])
+                .unwrap_or_default())
+        }
+    }
 }
 
 /// If there's a header, then retrieves the event list which should be denoted by having ` - ` in their names.
@@ -92,7 +393,7 @@ pub fn retrieve_event_list(headers: Option<Vec<String>>) -> error::Result<Vec<Ev
     if let Some(headers) = headers {
         let events = headers
             .into_iter()
-            .filter(|header| header.contains(" - ") && !header.contains(" - Nom du capitaine") && !header.contains(" - Nom de l'équipe") && !header.contains(" - Nom du groupe") && !header.contains(" - Nom du partenaire")) // FIXME: find a better way to exclude event details
+            .filter(|header| is_event_header(header))
             .enumerate()
             .map(|(index, name)| Event::new(index, name))
             .collect();
@@ -105,32 +406,379 @@ pub fn retrieve_event_list(headers: Option<Vec<String>>) -> error::Result<Vec<Ev
 
 #[cfg(test)]
 mod tests {
-    mod load_convention {
-        use super::super::load_convention;
+    mod normalize {
+        use crate::configuration::events_mapping::load_mappings;
+        use crate::error::ApplicationError;
+        use crate::registration::registrant::Registrant;
         use crate::test_data::*;
+        use std::collections::HashSet;
+        use std::path::PathBuf;
+
+        fn get_mapping_file() -> PathBuf {
+            PathBuf::from(format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR")))
+        }
 
         #[test]
         fn success() {
+            let convention = get_test_convention();
+            let mappings = load_mappings(&get_mapping_file()).unwrap();
+
+            let normalized = convention.normalize(&mappings).unwrap();
+
+            assert_eq!(convention.registrations().len(), normalized.registrations().len());
+            let event = normalized
+                .events()
+                .iter()
+                .find(|event| event.name() == "tout-terrain/cross-country")
+                .unwrap();
+            let participants: HashSet<&Registrant> = normalized.participants_by_event()[*event.index()].iter().collect();
+            let expected_participants: HashSet<&Registrant> = convention.participants_by_event()[18]
+                .iter()
+                .chain(convention.participants_by_event()[28].iter())
+                .collect();
+            assert_eq!(expected_participants, participants);
+            assert_eq!(expected_participants.len(), normalized.participants_by_event()[*event.index()].len());
+        }
+
+        #[test]
+        fn success_events_are_sorted_and_indexed() {
+            let convention = get_test_convention();
+            let mappings = load_mappings(&get_mapping_file()).unwrap();
+
+            let normalized = convention.normalize(&mappings).unwrap();
+
+            let names: Vec<&String> = normalized.events().iter().map(|event| event.name()).collect();
+            let mut sorted_names = names.clone();
+            sorted_names.sort();
+            assert_eq!(sorted_names, names);
+            assert!(!names.contains(&&"athletisme/stillstand".to_string()));
+            assert!(normalized.events().iter().enumerate().all(|(index, event)| *event.index() == index));
+        }
+
+        #[test]
+        fn fail_unmapped_event() {
+            let convention = get_test_convention();
+            let mappings = load_mappings(&get_test_asset("configuration/2025.yml")).unwrap();
+
+            let error = convention.normalize(&mappings).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::UnmappedEvent(_)));
+        }
+
+        #[test]
+        fn success_duplicate_headers() {
+            let convention = get_test_convention();
+            let mappings = load_mappings(&get_mapping_file()).unwrap();
+
+            let normalized = convention.normalize(&mappings).unwrap();
+
+            let participants_count = |name: &str| {
+                let event = normalized.events().iter().find(|event| event.name() == name).unwrap();
+                normalized.participants_by_event()[*event.index()].len()
+            };
+            assert_eq!(convention.participants_by_event()[8].len(), participants_count("athletisme/parcours-iuf"));
+        }
+
+        #[test]
+        fn fail_ambiguous_header() {
+            let convention = get_test_convention();
+            let mut mappings = load_mappings(&get_mapping_file()).unwrap();
+            mappings
+                .get_mut("athletisme")
+                .unwrap()
+                .insert("parcours-iuf".to_string(), vec!["Parcours IUF - All".into()]);
+
+            let error = convention.normalize(&mappings).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::AmbiguousHeader(header) if header == "Parcours IUF - All"));
+        }
+
+        #[test]
+        fn fail_ambiguous_column() {
+            let convention = get_test_convention();
+            let mut mappings = load_mappings(&get_mapping_file()).unwrap();
+            mappings
+                .get_mut("athletisme")
+                .unwrap()
+                .insert("stillstand".to_string(), vec!["400m - All".into()]);
+
+            let error = convention.normalize(&mappings).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::AmbiguousColumn { index: 5, .. }));
+        }
+    }
+
+    mod participants_by_discipline {
+        use crate::registration::registrant::Registrant;
+        use crate::test_data::*;
+        use std::collections::HashSet;
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let participants = convention.participants_by_discipline();
+
+            let ten_kilometers: HashSet<&Registrant> = participants["10 kilomètres"].iter().collect();
+            let expected: HashSet<&Registrant> = convention.participants_by_event()[14]
+                .iter()
+                .chain(convention.participants_by_event()[15].iter())
+                .collect();
+            assert_eq!(expected, ten_kilometers);
+            assert_eq!(expected.len(), participants["10 kilomètres"].len());
+            assert_eq!(convention.participants_by_event()[22], participants["Basket"]);
+        }
+    }
+
+    mod participants_by_class {
+        use crate::configuration::events_mapping::load_mappings;
+        use crate::registration::registrant::Registrant;
+        use crate::test_data::*;
+        use std::collections::HashSet;
+        use std::path::PathBuf;
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let participants = convention.participants_by_class();
+
+            let unlimited: HashSet<&Registrant> = participants["Illimité"].iter().collect();
+            let expected: HashSet<&Registrant> = convention.participants_by_event()[15]
+                .iter()
+                .chain(convention.participants_by_event()[17].iter())
+                .collect();
+            assert_eq!(expected, unlimited);
+            assert_eq!(vec!["All", "Illimité", "Standard 24\"", "Standard 29\""], participants.keys().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn success_canonical_events() {
+            let mapping = PathBuf::from(format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR")));
+            let convention = get_test_convention().normalize(&load_mappings(&mapping).unwrap()).unwrap();
+
+            assert!(convention.participants_by_class().is_empty());
+        }
+    }
+
+    mod teams {
+        use crate::configuration::events_mapping::load_mappings;
+        use crate::test_data::*;
+        use std::path::PathBuf;
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let teams = convention.teams(25);
+
+            let pair = teams.iter().find(|team| team.members().len() == 2).unwrap();
+            assert_eq!("Victor Bègue & Dominique Toussaint", pair.name());
+            assert!(pair.unregistered_members().is_empty());
+            assert_eq!(convention.participants_by_event()[25].len() - 1, teams.len());
+        }
+
+        #[test]
+        fn success_normalized() {
+            let mapping = PathBuf::from(format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR")));
+            let convention = get_test_convention().normalize(&load_mappings(&mapping).unwrap()).unwrap();
+            let event = convention.events().iter().find(|event| event.name() == "artistique/paire").unwrap();
+
+            let teams = convention.teams(*event.index());
+
+            assert!(teams.iter().any(|team| team.name() == "Victor Bègue & Dominique Toussaint"));
+        }
+    }
+
+    mod aggregate_by_category {
+        use crate::configuration::events_configuration::load_configuration;
+        use crate::configuration::events_mapping::load_mappings;
+        use crate::error::ApplicationError;
+        use crate::registration::registrant::Registrant;
+        use crate::test_data::*;
+        use std::collections::HashSet;
+        use std::path::PathBuf;
+
+        fn get_configuration_file(name: &str) -> PathBuf {
+            PathBuf::from(format!("{}/configuration/{name}", env!("CARGO_MANIFEST_DIR")))
+        }
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention().normalize(&load_mappings(&get_configuration_file("2016.yml")).unwrap()).unwrap();
+            let configuration = load_configuration(&get_configuration_file("events.yml")).unwrap();
+
+            let categories = convention.aggregate_by_category(&configuration).unwrap();
+
+            let names: Vec<&String> = categories.events().iter().map(|event| event.name()).collect();
+            assert!(names.contains(&&"Courses sur route".to_string()));
+            let road = categories.events().iter().find(|event| event.name() == "Courses sur route").unwrap();
+            let expected: HashSet<&Registrant> = convention
+                .events()
+                .iter()
+                .filter(|event| event.name().starts_with("courses-sur-route/"))
+                .flat_map(|event| convention.participants_by_event()[*event.index()].iter())
+                .collect();
+            let participants = &categories.participants_by_event()[*road.index()];
+            assert_eq!(expected.len(), participants.len());
+            assert_eq!(expected, participants.iter().collect());
+            assert_eq!(convention.registrations().len(), categories.registrations().len());
+        }
+
+        #[test]
+        fn fail_not_normalized() {
+            let configuration = load_configuration(&get_configuration_file("events.yml")).unwrap();
+
+            let error = get_test_convention().aggregate_by_category(&configuration).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::Configuration(_)));
+        }
+    }
+
+    mod resolve_columns {
+        use super::super::resolve_columns;
+        use crate::configuration::events_mapping::ColumnSelector;
+        use crate::test_data::get_test_convention;
+        use std::num::NonZeroUsize;
+
+        #[test]
+        fn success_occurrence() {
+            let convention = get_test_convention();
+            let selector = ColumnSelector::Disambiguated {
+                header: "Parcours IUF - All".to_string(),
+                occurrence: NonZeroUsize::new(2),
+                after: None,
+            };
+
+            assert_eq!(vec![8], resolve_columns(&selector, convention.events()).unwrap());
+        }
+
+        #[test]
+        fn success_after() {
+            let convention = get_test_convention();
+            let selector = ColumnSelector::Disambiguated {
+                header: "Parcours IUF - All".to_string(),
+                occurrence: None,
+                after: Some("400m - All".to_string()),
+            };
+
+            assert_eq!(vec![8], resolve_columns(&selector, convention.events()).unwrap());
+        }
+
+        #[test]
+        fn success_missing_column() {
+            let convention = get_test_convention();
+            let selector = ColumnSelector::Disambiguated {
+                header: "Parcours IUF - All".to_string(),
+                occurrence: NonZeroUsize::new(3),
+                after: None,
+            };
+
+            assert!(resolve_columns(&selector, convention.events()).unwrap().is_empty());
+        }
+    }
+
+    mod load_convention {
+        use super::super::{load_convention, RegistrationSource};
+        use crate::registration::diagnostic::{Diagnostic, LoadingMode, Severity};
+        use crate::registration::loading_options::LoadingOptions;
+        use chrono::NaiveDate;
+        use crate::registration::spreadsheet::SheetSelection;
+        use crate::test_data::*;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            asset = { "registration/registrations.xls", "registration/registrations.xlsx", "registration/registrations.ods", "registration/registrations.csv" },
+        )]
+        fn success(asset: &str) {
             let expected_convention = get_test_convention();
 
+            let path = get_test_asset(asset);
+            let (convention, diagnostics) = load_convention(&path, &LoadingOptions::default()).unwrap();
+
+            let source = convention.source().clone().unwrap();
+            assert_eq!((2..=51).collect::<Vec<u32>>(), *source.rows());
+            assert_eq!("Id", source.id_column());
+            assert_eq!(expected_convention.with_source(Some(source)), convention);
+            assert!(diagnostics.is_empty());
+        }
+
+        #[test]
+        fn success_first_sheet() {
+            let path = get_test_asset("registration/wrong_sheet_name.xls");
+            let options = LoadingOptions::default().with_sheet(SheetSelection::First);
+            let (convention, _) = load_convention(&path, &options).unwrap();
+
+            assert_eq!(50, convention.registrations().len());
+        }
+
+        #[test]
+        fn success_lenient() {
+            let path = get_test_asset("registration/malformed_registrations.csv");
+            let options = LoadingOptions::default().with_mode(LoadingMode::Lenient);
+            let (convention, diagnostics) = load_convention(&path, &options).unwrap();
+
+            assert_eq!(2, convention.registrations().len());
+            assert_eq!(
+                &Some(RegistrationSource::new("malformed_registrations.csv".to_string(), "Id".to_string(), vec![2, 4])),
+                convention.source()
+            );
+            assert_eq!(
+                vec![
+                    Diagnostic::new(Severity::Warning, "malformed_registrations.csv".to_string(), 2, "Basket - All".to_string(), "Peut-être".to_string(), "A cell has a wrong format: `Peut-être` is not a known registration value".to_string()),
+                    Diagnostic::new(Severity::Error, "malformed_registrations.csv".to_string(), 3, "Age".to_string(), "douze".to_string(), "A cell has a wrong format: age has the wrong format".to_string()),
+                    Diagnostic::new(Severity::Error, "malformed_registrations.csv".to_string(), 5, "Last Name".to_string(), "".to_string(), "A cell has a wrong format: last_name has the wrong format".to_string()),
+                    Diagnostic::new(Severity::Error, "malformed_registrations.csv".to_string(), 5, "Gender".to_string(), "Unknown".to_string(), "A cell has a wrong format: Gender should be a male, female, non-binary or unspecified label. Got `Unknown` instead.".to_string()),
+                ],
+                diagnostics
+            );
+        }
+
+        #[test]
+        fn success_start_date() {
             let path = get_test_asset("registration/registrations.xls");
-            let convention = load_convention(&path).unwrap();
+            let start_date = NaiveDate::from_ymd_opt(2025, 5, 29).unwrap();
+            let options = LoadingOptions::default().with_start_date(start_date);
+            let (convention, diagnostics) = load_convention(&path, &options).unwrap();
+
+            let (registrant, _) = &convention.registrations()[0];
+            assert_eq!(58, *registrant.age());
+            assert!(diagnostics.iter().all(|diagnostic| *diagnostic.severity() == Severity::Warning));
+            assert!(diagnostics.iter().any(|diagnostic| diagnostic.row() == &2
+                && diagnostic.column() == "Age"
+                && diagnostic.value() == "59"
+                && diagnostic.reason() == "The registrant is 58 at the convention's start date (2025-05-29)."));
+        }
+
+        #[test]
+        fn fail_strict() {
+            let path = get_test_asset("registration/malformed_registrations.csv");
+            let error = load_convention(&path, &LoadingOptions::default()).unwrap_err();
 
-            assert_eq!(expected_convention, convention);
+            assert_eq!(
+                "Error: malformed_registrations.csv, row 3, column `Age` (`douze`): A cell has a wrong format: age has the wrong format",
+                error.to_string()
+            );
         }
 
         #[test]
         #[should_panic(expected = "NotFound")]
         fn fail_on_file_not_found() {
             let path = get_test_asset("registration/not_found.xls");
-            load_convention(&path).unwrap();
+            load_convention(&path, &LoadingOptions::default()).unwrap();
         }
 
         #[test]
         #[should_panic(expected = "WorksheetNotFound")]
         fn fail_on_sheet_not_found() {
             let path = get_test_asset("registration/wrong_sheet_name.xls");
-            load_convention(&path).unwrap();
+            load_convention(&path, &LoadingOptions::default()).unwrap();
         }
     }
 
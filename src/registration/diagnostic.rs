@@ -0,0 +1,148 @@
+//! Problems found while loading a registration export, located down to the cell.
+
+use crate::error;
+use crate::error::ApplicationError;
+use derive_getters::Getters;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Whether the problem made the row be left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The row could not be loaded.
+    Error,
+    /// The row has been loaded, but something looks suspicious.
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "Error"),
+            Severity::Warning => write!(f, "Warning"),
+        }
+    }
+}
+
+/// A problem found in a cell of the export.
+#[derive(Debug, Getters, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    severity: Severity,
+    sheet: String,
+    /// 1-based, as displayed by spreadsheet software.
+    row: u32,
+    /// Header of the cell's column.
+    column: String,
+    /// Raw value of the cell.
+    value: String,
+    reason: String,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: Severity,
+        sheet: String,
+        row: u32,
+        column: String,
+        value: String,
+        reason: String,
+    ) -> Self {
+        Self {
+            severity,
+            sheet,
+            row,
+            column,
+            value,
+            reason,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {}, row {}, column `{}` (`{}`): {}",
+            self.severity, self.sheet, self.row, self.column, self.value, self.reason
+        )
+    }
+}
+
+/// Whether a malformed row makes the whole loading fail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadingMode {
+    /// The first malformed row makes the loading fail.
+    #[default]
+    Strict,
+    /// Malformed rows are left out of the convention and reported as diagnostics.
+    /// Warnings are reported in both modes.
+    Lenient,
+}
+
+/// Write the diagnostics as a semicolon separated table.
+pub fn export_diagnostics(diagnostics: &[Diagnostic], path: &Path) -> error::Result<()> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(b';')
+        .from_path(path)?;
+    for diagnostic in diagnostics {
+        writer.serialize(diagnostic)?;
+    }
+    writer.flush().map_err(|source| ApplicationError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod display {
+        use crate::registration::diagnostic::{Diagnostic, Severity};
+
+        #[test]
+        fn success() {
+            let diagnostic = Diagnostic::new(
+                Severity::Error,
+                "Worksheet1".to_string(),
+                12,
+                "Age".to_string(),
+                "douze".to_string(),
+                "age has the wrong format".to_string(),
+            );
+
+            assert_eq!(
+                "Error: Worksheet1, row 12, column `Age` (`douze`): age has the wrong format",
+                diagnostic.to_string()
+            );
+        }
+    }
+
+    mod export_diagnostics {
+        use crate::registration::diagnostic::{export_diagnostics, Diagnostic, Severity};
+        use std::env::temp_dir;
+        use std::fs::read_to_string;
+
+        #[test]
+        fn success() {
+            let file = temp_dir().join("diagnostics.csv");
+            let diagnostics = vec![Diagnostic::new(
+                Severity::Error,
+                "Worksheet1".to_string(),
+                12,
+                "Age".to_string(),
+                "douze".to_string(),
+                "age has the wrong format".to_string(),
+            )];
+
+            export_diagnostics(&diagnostics, &file).unwrap();
+
+            assert_eq!(
+                "severity;sheet;row;column;value;reason\nerror;Worksheet1;12;Age;douze;age has the wrong format\n",
+                read_to_string(file).unwrap()
+            );
+        }
+    }
+}
@@ -1,11 +1,19 @@
 use std::cmp::Ordering;
 use derive_getters::Getters;
 
+/// Separates the discipline from the class in an event's header, e.g. `10 kilomètres - Standard 24"`.
+const CLASS_SEPARATOR: &str = " - ";
+
 #[derive(Debug, Getters, PartialEq, Eq, Hash, Clone)]
 pub struct Event {
     /// The index refers to the column's index, starting from the first event
     index: usize,
-    name: String
+    name: String,
+    /// What is ridden, e.g. `10 kilomètres` or `Basket A`.
+    discipline: String,
+    /// Who competes together, e.g. a wheel size (`Standard 24"`), a division (`Illimité`) or `All`.
+    /// Canonical events have no class.
+    class: Option<String>,
 }
 
 impl PartialOrd for Event {
@@ -23,6 +31,51 @@ impl Ord for Event {
 
 impl Event {
     pub fn new(index: usize, name: String) -> Self {
-        Self { index, name }
+        let (discipline, class) = split_name(&name);
+        let (discipline, class) = (discipline.to_string(), class.map(str::to_string));
+        Self { index, name, discipline, class }
+    }
+}
+
+/// Split an event's name into its discipline and its class, if any.
+/// Only the last separator counts, a discipline such as `Marathon (42,195 km)` possibly holding dashes.
+pub fn split_name(name: &str) -> (&str, Option<&str>) {
+    match name.rsplit_once(CLASS_SEPARATOR) {
+        Some((discipline, class)) => (discipline.trim(), Some(class.trim())),
+        None => (name.trim(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod new {
+        use crate::registration::event::Event;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            name = { "10 kilomètres - Standard 24\"", "Marathon (42,195 km) - Illimité", "Basket A - All", "Relais 4 x 100m - All" },
+            discipline = { "10 kilomètres", "Marathon (42,195 km)", "Basket A", "Relais 4 x 100m" },
+            class = { "Standard 24\"", "Illimité", "All", "All" },
+        )]
+        fn success(name: &str, discipline: &str, class: &str) {
+            let event = Event::new(0, name.to_string());
+
+            assert_eq!(discipline, event.discipline());
+            assert_eq!(&Some(class.to_string()), event.class());
+        }
+
+        #[test]
+        fn success_canonical_event() {
+            let event = Event::new(0, "route/10-km".to_string());
+
+            assert_eq!("route/10-km", event.discipline());
+            assert_eq!(&None, event.class());
+        }
     }
 }
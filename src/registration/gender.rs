@@ -1,25 +1,59 @@
+use crate::configuration::import_configuration::GenderLabels;
 use crate::error;
 use crate::error::ApplicationError;
 use crate::error::ApplicationError::WrongFormat;
-use crate::registration::gender::Gender::{Female, Male};
+use crate::registration::gender::Gender::{Female, Male, NonBinary, Unspecified};
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialOrd, PartialEq, Clone, Hash, Ord, Eq)]
 pub enum Gender {
     Male,
     Female,
+    NonBinary,
+    /// The registrant chose not to tell, or the export does not say.
+    Unspecified,
+}
+
+impl Gender {
+    /// Find the gender given label stands for.
+    pub fn parse(value: &str, labels: &GenderLabels) -> error::Result<Self> {
+        let value = value.trim();
+        let matches = |labels: &[String]| labels.iter().any(|label| label.to_lowercase() == value.to_lowercase());
+
+        if matches(labels.male()) {
+            Ok(Male)
+        } else if matches(labels.female()) {
+            Ok(Female)
+        } else if matches(labels.non_binary()) {
+            Ok(NonBinary)
+        } else if value.is_empty() || matches(labels.unspecified()) {
+            Ok(Unspecified)
+        } else {
+            Err(WrongFormat(format!(
+                "Gender should be a male, female, non-binary or unspecified label. Got `{value}` instead.",
+            )))
+        }
+    }
+}
+
+impl Display for Gender {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Male => "Male",
+            Female => "Female",
+            NonBinary => "Non-binary",
+            Unspecified => "Unspecified",
+        };
+        write!(f, "{label}")
+    }
 }
 
 impl TryFrom<&String> for Gender {
     type Error = ApplicationError;
 
+    /// Parse the gender using the default labels.
     fn try_from(value: &String) -> error::Result<Self, Self::Error> {
-        match value as &str {
-            "Male" => Ok(Male),
-            "Female" => Ok(Female),
-            _ => Err(WrongFormat(format!(
-                "Gender should be `Male` or `Female`. Got `{value}` instead.",
-            ))),
-        }
+        Gender::parse(value, &GenderLabels::default())
     }
 }
 
@@ -36,8 +70,8 @@ mod tests {
         }
 
         #[parameterized(
-            gender_string = { "Female", "Male" },
-            expected_gender = { Gender::Female, Gender::Male }
+            gender_string = { "Female", "Male", "Femme", "homme", "F", "H", "Non-binaire", "Non précisé", "" },
+            expected_gender = { Gender::Female, Gender::Male, Gender::Female, Gender::Male, Gender::Female, Gender::Male, Gender::NonBinary, Gender::Unspecified, Gender::Unspecified }
         )]
         fn success(gender_string: &str, expected_gender: Gender) {
             assert_eq!(
@@ -47,11 +81,26 @@ mod tests {
         }
 
         #[parameterized(
-            gender_string = { "", "x" },
+            gender_string = { "x", "Mâle" },
         )]
-        #[should_panic(expected = "Gender should be `Male` or `Female`.")]
+        #[should_panic(expected = "Gender should be a male, female, non-binary or unspecified label.")]
         fn fail(gender_string: &str) {
             Gender::try_from(&String::from(gender_string)).unwrap();
         }
     }
+
+    mod parse {
+        use crate::configuration::import_configuration::{load_import_configuration, GenderLabels};
+        use crate::registration::gender::Gender;
+        use crate::test_data::get_test_asset;
+
+        #[test]
+        fn success_custom_labels() {
+            let configuration = load_import_configuration(&get_test_asset("configuration/import.yml")).unwrap();
+
+            assert_eq!(Gender::Male, Gender::parse("garçon", configuration.genders()).unwrap());
+            assert!(Gender::parse("Male", configuration.genders()).is_err());
+            assert_eq!(Gender::Male, Gender::parse("Male", &GenderLabels::default()).unwrap());
+        }
+    }
 }
@@ -0,0 +1,124 @@
+//! Every year of the convention, to compute statistics across years.
+
+use crate::configuration::events_mapping::load_mappings;
+use crate::configuration::history_manifest::HistoryManifest;
+use crate::error;
+use crate::registration::convention::{load_convention, Convention};
+use crate::registration::diagnostic::Diagnostic;
+use crate::registration::loading_options::LoadingOptions;
+use std::collections::BTreeMap;
+
+/// Conventions of several years, each normalized through its year's mapping,
+/// so that their events are the same canonical `category/event` events.
+#[derive(Debug, PartialEq)]
+pub struct ConventionHistory {
+    conventions: BTreeMap<u16, Convention>,
+}
+
+impl ConventionHistory {
+    /// Load and normalize every year of the manifest.
+    /// A year's start date, when given, supersedes the one of the options.
+    ///
+    /// Diagnostics are returned along with their year, see [load_convention].
+    pub fn load(
+        manifest: &HistoryManifest,
+        options: &LoadingOptions,
+    ) -> error::Result<(Self, Vec<(u16, Diagnostic)>)> {
+        let mut conventions = BTreeMap::new();
+        let mut diagnostics = vec![];
+
+        for (year, entry) in manifest {
+            let options = match entry.start_date() {
+                Some(start_date) => options.clone().with_start_date(*start_date),
+                None => options.clone(),
+            };
+            let (convention, year_diagnostics) = load_convention(entry.file(), &options)?;
+            let convention = convention.normalize(&load_mappings(entry.mapping())?)?;
+
+            diagnostics.extend(year_diagnostics.into_iter().map(|diagnostic| (*year, diagnostic)));
+            conventions.insert(*year, convention);
+        }
+
+        Ok((Self { conventions }, diagnostics))
+    }
+
+    #[cfg(test)]
+    pub fn new(conventions: BTreeMap<u16, Convention>) -> Self {
+        Self { conventions }
+    }
+
+    /// Years of the history, in chronological order.
+    pub fn years(&self) -> impl Iterator<Item = u16> + '_ {
+        self.conventions.keys().copied()
+    }
+
+    pub fn get(&self, year: u16) -> Option<&Convention> {
+        self.conventions.get(&year)
+    }
+
+    /// Every convention along with its year, in chronological order.
+    pub fn conventions(&self) -> impl Iterator<Item = (u16, &Convention)> {
+        self.conventions.iter().map(|(year, convention)| (*year, convention))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod load {
+        use crate::configuration::history_manifest::{load_manifest, scan_directory, YearEntry};
+        use crate::error::ApplicationError;
+        use crate::registration::diagnostic::Severity;
+        use crate::registration::history::ConventionHistory;
+        use crate::registration::loading_options::LoadingOptions;
+        use crate::test_data::get_test_asset;
+
+        #[test]
+        fn success() {
+            let manifest = load_manifest(&get_test_asset("history/manifest.yml")).unwrap();
+
+            let (history, diagnostics) = ConventionHistory::load(&manifest, &LoadingOptions::default()).unwrap();
+
+            assert_eq!(vec![2016, 2017], history.years().collect::<Vec<_>>());
+            assert_eq!(50, history.get(2016).unwrap().registrations().len());
+            assert_eq!(30, history.get(2017).unwrap().registrations().len());
+            assert_eq!(history.get(2016).unwrap().events(), history.get(2017).unwrap().events());
+            assert!(history.get(2016).unwrap().events().iter().any(|event| event.name() == "tout-terrain/cross-country"));
+            assert!(diagnostics
+                .iter()
+                .all(|(year, diagnostic)| *year == 2017 && *diagnostic.severity() == Severity::Warning));
+        }
+
+        #[test]
+        fn success_start_date() {
+            let manifest = load_manifest(&get_test_asset("history/manifest.yml")).unwrap();
+
+            let (history, _) = ConventionHistory::load(&manifest, &LoadingOptions::default()).unwrap();
+
+            let (registrant, _) = &history.get(2017).unwrap().registrations()[0];
+            assert_eq!(50, *registrant.age());
+            let (registrant, _) = &history.get(2016).unwrap().registrations()[0];
+            assert_eq!(59, *registrant.age());
+        }
+
+        #[test]
+        fn success_directory() {
+            let manifest = scan_directory(&get_test_asset("history/exports"), &get_test_asset("history/mappings")).unwrap();
+
+            let (history, diagnostics) = ConventionHistory::load(&manifest, &LoadingOptions::default()).unwrap();
+
+            assert_eq!(2, history.conventions().count());
+            assert!(diagnostics.is_empty());
+        }
+
+        #[test]
+        fn fail_unmapped_event() {
+            let mut manifest = load_manifest(&get_test_asset("history/manifest.yml")).unwrap();
+            let file = manifest[&2017].file().clone();
+            manifest.insert(2017, YearEntry::new(file, get_test_asset("configuration/2025.yml"), None));
+
+            let error = ConventionHistory::load(&manifest, &LoadingOptions::default()).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::UnmappedEvent(_)));
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! Recognizing the same rider from one year to the next.
+//!
+//! Registrant ids are assigned anew every year, and ages or clubs change:
+//! riders are matched on their names and birthday instead.
+
+use crate::configuration::identity_overrides::{IdentityOverrides, RegistrationReference};
+use crate::registration::diagnostic::{Diagnostic, Severity};
+use crate::registration::history::ConventionHistory;
+use crate::registration::registrant::Registrant;
+use chrono::NaiveDate;
+use derive_getters::Getters;
+use std::collections::{BTreeMap, HashSet};
+
+/// Typos tolerated between the names of two registrants born the same day for them to be the same rider.
+const DEFAULT_MAX_DISTANCE: usize = 2;
+
+/// How registrations of different years are matched to riders.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct IdentityResolver {
+    overrides: IdentityOverrides,
+    /// Maximum edit distance between two folded full names, when no registrant matches exactly.
+    max_distance: usize,
+}
+
+impl Default for IdentityResolver {
+    fn default() -> Self {
+        Self {
+            overrides: IdentityOverrides::default(),
+            max_distance: DEFAULT_MAX_DISTANCE,
+        }
+    }
+}
+
+/// Stable rider id of every registration of a history.
+/// Rider ids start from 0, in the order riders first registered.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct RiderIdentities {
+    riders: BTreeMap<RegistrationReference, usize>,
+    riders_count: usize,
+}
+
+impl RiderIdentities {
+    /// Rider id of a registrant of given year.
+    pub fn rider(&self, year: u16, registrant: &Registrant) -> Option<usize> {
+        self.riders.get(&RegistrationReference::new(year, *registrant.id())).copied()
+    }
+
+    /// Registrations of a rider, in chronological order.
+    pub fn registrations_of(&self, rider: usize) -> Vec<RegistrationReference> {
+        self.riders
+            .iter()
+            .filter(|(_, other)| **other == rider)
+            .map(|(reference, _)| *reference)
+            .collect()
+    }
+}
+
+/// A rider, as known from previous years.
+struct KnownRider {
+    first_name: String,
+    last_name: String,
+    birthday: NaiveDate,
+    registrations: Vec<RegistrationReference>,
+}
+
+impl IdentityResolver {
+    pub fn with_overrides(mut self, overrides: IdentityOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    pub fn with_max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Assign a rider id to every registration of the history, year after year.
+    ///
+    /// A registrant is the rider of a previous year:
+    /// 1. whom the overrides designate as the same,
+    /// 2. otherwise, with the same birthday and the same names, regardless of case and accents,
+    /// 3. otherwise, with the same birthday and the closest names, within [IdentityResolver::max_distance].
+    ///
+    /// A rider matches at most one registrant a year, and never one the overrides designate as different.
+    /// Overrides designating a rider already matched that year are ignored.
+    ///
+    /// Registrations sharing the id of a previous registration of the same year are left out.
+    /// Both cases are returned as warnings along with the year, located in the export of the convention if loaded from one.
+    pub fn resolve(&self, history: &ConventionHistory) -> (RiderIdentities, Vec<(u16, Diagnostic)>) {
+        let mut known_riders: Vec<KnownRider> = vec![];
+        let mut riders: BTreeMap<RegistrationReference, usize> = BTreeMap::new();
+        let mut diagnostics = vec![];
+
+        for (year, convention) in history.conventions() {
+            let mut matched_this_year: HashSet<usize> = HashSet::new();
+            for (index, (registrant, _)) in convention.registrations().iter().enumerate() {
+                let reference = RegistrationReference::new(year, *registrant.id());
+                let warning = |reason: String| {
+                    let (sheet, column, row) = match convention.source() {
+                        Some(source) => (source.sheet().clone(), source.id_column().clone(), source.rows()[index]),
+                        None => Default::default(),
+                    };
+                    (year, Diagnostic::new(Severity::Warning, sheet, row, column, registrant.id().to_string(), reason))
+                };
+                if riders.contains_key(&reference) {
+                    diagnostics.push(warning(format!(
+                        "Registration id {} is used twice in {year}, the registration is left out of the riders.",
+                        registrant.id()
+                    )));
+                    continue;
+                }
+                let first_name = fold(registrant.first_name());
+                let last_name = fold(registrant.last_name());
+
+                let forced_rider = self.forced_rider(reference, &riders);
+                let forced_rider = match forced_rider {
+                    Some(rider) if matched_this_year.contains(&rider) => {
+                        diagnostics.push(warning(format!(
+                            "The overrides designate {year}/{} as a rider already registered in {year}, they are ignored.",
+                            registrant.id()
+                        )));
+                        None
+                    }
+                    rider => rider,
+                };
+                let is_candidate = |rider: &usize| {
+                    !matched_this_year.contains(rider) && !self.are_different(reference, &known_riders[*rider].registrations)
+                };
+                let rider = forced_rider
+                    .or_else(|| {
+                        (0..known_riders.len()).filter(is_candidate).find(|rider| {
+                            let known = &known_riders[*rider];
+                            known.birthday == *registrant.birthday()
+                                && known.first_name == first_name
+                                && known.last_name == last_name
+                        })
+                    })
+                    .or_else(|| {
+                        (0..known_riders.len())
+                            .filter(is_candidate)
+                            .filter(|rider| known_riders[*rider].birthday == *registrant.birthday())
+                            .map(|rider| (names_distance(&known_riders[rider], &first_name, &last_name), rider))
+                            .filter(|(distance, _)| *distance <= self.max_distance)
+                            .min()
+                            .map(|(_, rider)| rider)
+                    });
+
+                let rider = match rider {
+                    Some(rider) => {
+                        known_riders[rider].registrations.push(reference);
+                        rider
+                    }
+                    None => {
+                        known_riders.push(KnownRider {
+                            first_name,
+                            last_name,
+                            birthday: *registrant.birthday(),
+                            registrations: vec![reference],
+                        });
+                        known_riders.len() - 1
+                    }
+                };
+                matched_this_year.insert(rider);
+                riders.insert(reference, rider);
+            }
+        }
+
+        let identities = RiderIdentities {
+            riders,
+            riders_count: known_riders.len(),
+        };
+        (identities, diagnostics)
+    }
+
+    /// Rider of an already identified registration the overrides designate as the same.
+    fn forced_rider(
+        &self,
+        reference: RegistrationReference,
+        riders: &BTreeMap<RegistrationReference, usize>,
+    ) -> Option<usize> {
+        self.overrides
+            .same()
+            .iter()
+            .filter(|group| group.contains(&reference))
+            .flatten()
+            .find_map(|other| riders.get(other).copied())
+    }
+
+    fn are_different(&self, reference: RegistrationReference, registrations: &[RegistrationReference]) -> bool {
+        self.overrides.different().iter().any(|group| {
+            group.contains(&reference) && registrations.iter().any(|registration| group.contains(registration))
+        })
+    }
+}
+
+/// List every registration of the history along with its rider id, as a semicolon separated table.
+pub fn generate_csv_file(history: &ConventionHistory, identities: &RiderIdentities) -> String {
+    let mut content = "Rider;Year;Id;First Name;Last Name;Birthday".to_string();
+    for (year, convention) in history.conventions() {
+        for (registrant, _) in convention.registrations() {
+            let rider = identities.rider(year, registrant).map(|rider| rider.to_string()).unwrap_or_default();
+            content = format!(
+                "{content}\n{rider};{year};{};{};{};{}",
+                registrant.id(),
+                registrant.first_name(),
+                registrant.last_name(),
+                registrant.birthday()
+            );
+        }
+    }
+
+    content
+}
+
+/// Names are compared without case, accents, dashes or extra spaces: `Jean-Rémi` matches `JEAN REMI`.
+fn fold(name: &str) -> String {
+    deunicode::deunicode(name)
+        .to_lowercase()
+        .replace('-', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Edit distance between the full names, first and last names possibly swapped.
+fn names_distance(known: &KnownRider, first_name: &str, last_name: &str) -> usize {
+    let known_name = format!("{} {}", known.first_name, known.last_name);
+    let name = format!("{first_name} {last_name}");
+    let swapped_name = format!("{last_name} {first_name}");
+
+    strsim::levenshtein(&known_name, &name).min(strsim::levenshtein(&known_name, &swapped_name))
+}
+
+#[cfg(test)]
+mod tests {
+    mod resolve {
+        use crate::configuration::identity_overrides::{load_identity_overrides, IdentityOverrides, RegistrationReference};
+        use crate::registration::convention::{Convention, RegistrationSource};
+        use crate::registration::gender::Gender;
+        use crate::registration::history::ConventionHistory;
+        use crate::registration::identity::IdentityResolver;
+        use crate::test_data::{get_test_asset, get_test_history, get_test_registrant};
+        use std::collections::BTreeMap;
+
+        /// A convention of given year for each list of registrant ids, the row 3 of each sheet having been left out.
+        fn history(years: Vec<(u16, Vec<u16>)>) -> ConventionHistory {
+            let conventions = years
+                .into_iter()
+                .map(|(year, ids)| {
+                    let registrations = ids.iter().map(|id| (get_test_registrant(*id, 16, Gender::Female, None), vec![])).collect();
+                    let rows = (0..ids.len() as u32).map(|index| if index == 0 { 2 } else { index + 3 }).collect();
+                    let source = RegistrationSource::new("Worksheet1".to_string(), "Id".to_string(), rows);
+                    (year, Convention::new(registrations, vec![vec![]; ids.len()], vec![], vec![]).with_source(Some(source)))
+                })
+                .collect::<BTreeMap<_, _>>();
+            ConventionHistory::new(conventions)
+        }
+
+        #[test]
+        fn success() {
+            let history = get_test_history();
+
+            let (identities, diagnostics) = IdentityResolver::default().resolve(&history);
+
+            assert!(diagnostics.is_empty());
+            let (first_2016, second_2016) = (&history.get(2016).unwrap().registrations()[0].0, &history.get(2016).unwrap().registrations()[1].0);
+            let (first_2017, second_2017) = (&history.get(2017).unwrap().registrations()[0].0, &history.get(2017).unwrap().registrations()[1].0);
+            // Case and accents differ
+            assert_eq!(identities.rider(2016, first_2016), identities.rider(2017, first_2017));
+            // A letter is missing from the last name
+            assert_eq!(identities.rider(2016, second_2016), identities.rider(2017, second_2017));
+            // The birthday differs
+            let third_2017 = &history.get(2017).unwrap().registrations()[2].0;
+            assert_eq!(Some(50), identities.rider(2017, third_2017));
+            assert_eq!(51, *identities.riders_count());
+            assert_eq!(80, identities.riders().len());
+        }
+
+        #[test]
+        fn success_no_fuzzy_matching() {
+            let history = get_test_history();
+
+            let identities = IdentityResolver::default().with_max_distance(0).resolve(&history).0;
+
+            let second_2017 = &history.get(2017).unwrap().registrations()[1].0;
+            assert_eq!(Some(50), identities.rider(2017, second_2017));
+            assert_eq!(52, *identities.riders_count());
+        }
+
+        #[test]
+        fn success_overrides() {
+            let history = get_test_history();
+            let overrides = load_identity_overrides(&get_test_asset("history/identity-overrides.yml")).unwrap();
+
+            let identities = IdentityResolver::default().with_overrides(overrides).resolve(&history).0;
+
+            let registrations_2016 = history.get(2016).unwrap().registrations();
+            let registrations_2017 = history.get(2017).unwrap().registrations();
+            assert_eq!(identities.rider(2016, &registrations_2016[2].0), identities.rider(2017, &registrations_2017[2].0));
+            assert_ne!(identities.rider(2016, &registrations_2016[3].0), identities.rider(2017, &registrations_2017[3].0));
+            assert_eq!(51, *identities.riders_count());
+            assert_eq!(2, identities.registrations_of(0).len());
+        }
+
+        #[test]
+        fn success_duplicate_id() {
+            let history = history(vec![(2016, vec![1, 2, 1])]);
+
+            let (identities, diagnostics) = IdentityResolver::default().resolve(&history);
+
+            assert_eq!(2, *identities.riders_count());
+            assert_eq!(2, identities.riders().len());
+            assert_eq!(1, diagnostics.len());
+            let (year, diagnostic) = &diagnostics[0];
+            assert_eq!(2016, *year);
+            assert_eq!(
+                "Warning: Worksheet1, row 5, column `Id` (`1`): Registration id 1 is used twice in 2016, the registration is left out of the riders.",
+                diagnostic.to_string()
+            );
+        }
+
+        #[test]
+        fn success_conflicting_overrides() {
+            let history = history(vec![(2016, vec![1]), (2017, vec![1, 2])]);
+            let same = vec![[(2016, 1), (2017, 1), (2017, 2)].map(|(year, id)| RegistrationReference::new(year, id)).to_vec()];
+
+            let (identities, diagnostics) = IdentityResolver::default()
+                .with_overrides(IdentityOverrides::new(same, vec![]))
+                .resolve(&history);
+
+            assert_eq!(2, *identities.riders_count());
+            assert_eq!(2, identities.registrations_of(0).len());
+            assert_eq!(1, identities.registrations_of(1).len());
+            assert_eq!(1, diagnostics.len());
+            assert_eq!((2017, 4), (diagnostics[0].0, *diagnostics[0].1.row()));
+        }
+    }
+
+    mod generate_csv_file {
+        use crate::registration::identity::{generate_csv_file, IdentityResolver};
+        use crate::test_data::get_test_history;
+
+        #[test]
+        fn success() {
+            let history = get_test_history();
+            let identities = IdentityResolver::default().resolve(&history).0;
+
+            let content = generate_csv_file(&history, &identities);
+
+            assert_eq!(81, content.lines().count());
+            assert!(content.contains("\n0;2016;1;Victor;Bègue;1966-12-31\n"));
+            assert!(content.contains("\n0;2017;101;VICTOR;BEGUE;1966-12-31\n"));
+        }
+    }
+
+    mod fold {
+        use crate::registration::identity::fold;
+
+        #[test]
+        fn success() {
+            assert_eq!("jean remi begue", fold(" Jean-Rémi  BÈGUE"));
+        }
+    }
+}
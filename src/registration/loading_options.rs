@@ -0,0 +1,38 @@
+use crate::configuration::import_configuration::ImportConfiguration;
+use crate::registration::diagnostic::LoadingMode;
+use crate::registration::spreadsheet::SheetSelection;
+use chrono::NaiveDate;
+use derive_getters::Getters;
+
+/// How a registration export should be read.
+/// The default options match the exports of the registration platform.
+#[derive(Debug, Getters, Clone, Default, PartialEq, Eq)]
+pub struct LoadingOptions {
+    sheet: SheetSelection,
+    import: ImportConfiguration,
+    mode: LoadingMode,
+    /// When known, registrants' ages are computed at this date rather than read from the export.
+    start_date: Option<NaiveDate>,
+}
+
+impl LoadingOptions {
+    pub fn with_sheet(mut self, sheet: SheetSelection) -> Self {
+        self.sheet = sheet;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: LoadingMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_start_date(mut self, start_date: NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn with_import(mut self, import: ImportConfiguration) -> Self {
+        self.import = import;
+        self
+    }
+}
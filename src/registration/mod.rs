@@ -1,5 +1,11 @@
+pub mod column_layout;
 pub mod convention;
+pub mod diagnostic;
 pub mod event;
 pub mod gender;
+pub mod history;
+pub mod identity;
+pub mod loading_options;
 pub mod registrant;
-
+pub mod spreadsheet;
+pub mod team;
@@ -1,7 +1,11 @@
+use crate::configuration::import_configuration::{GenderLabels, ImportConfiguration, RegistrationValues};
+use crate::error::ApplicationError;
 use crate::error::ApplicationError::{MisformattedRow, WrongFormat};
 use crate::error::Result;
+use crate::registration::column_layout::ColumnLayout;
 use crate::registration::gender::Gender;
-use calamine::Data;
+use calamine::{Data, DataType};
+use chrono::{NaiveDate, NaiveDateTime};
 use derive_getters::Getters;
 
 #[derive(Debug, Getters, PartialOrd, PartialEq, Clone, Hash, Eq)]
@@ -9,7 +13,7 @@ pub struct Registrant {
     id: u16,
     first_name: String,
     last_name: String,
-    birthday: String,
+    birthday: NaiveDate,
     age: u8,
     gender: Gender,
     club: Option<String>,
@@ -20,7 +24,7 @@ impl Registrant {
         id: u16,
         first_name: String,
         last_name: String,
-        birthday: String,
+        birthday: NaiveDate,
         age: u8,
         gender: Gender,
         club: Option<String>,
@@ -35,19 +39,60 @@ impl Registrant {
             club,
         }
     }
+
+    /// Age of the registrant on given date, e.g. the convention's start date.
+    pub fn age_at(&self, date: NaiveDate) -> u8 {
+        date.years_since(self.birthday)
+            .and_then(|age| age.try_into().ok())
+            .unwrap_or_default()
+    }
+
+    pub fn with_age(mut self, age: u8) -> Self {
+        self.age = age;
+        self
+    }
 }
 
-const EVENT_REGISTRATION_STRING: &str = "VRAI";
+/// Textual birthdays are usually French-style (`31.12.1966`), sometimes ISO.
+const BIRTHDAY_FORMATS: [&str; 4] = ["%d.%m.%Y", "%d/%m/%Y", "%d-%m-%Y", "%Y-%m-%d"];
+
+/// An error on a given cell of a row.
+#[derive(Debug, Getters)]
+pub struct CellError {
+    column: usize,
+    error: ApplicationError,
+}
 
 /// Create a [Registrant] and its list of registered events from a spreadsheet row.
-pub fn parse_row(row: &[Data]) -> Result<(Registrant, Vec<usize>)> {
-    let id = extract_id(row.first().ok_or(MisformattedRow)?)?;
-    let first_name = extract_first_name(row.get(1).ok_or(MisformattedRow)?)?;
-    let last_name = extract_last_name(row.get(2).ok_or(MisformattedRow)?)?;
-    let birthday = extract_birthday(row.get(3).ok_or(MisformattedRow)?)?;
-    let age = extract_age(row.get(4).ok_or(MisformattedRow)?)?;
-    let gender = extract_gender(row.get(5).ok_or(MisformattedRow)?)?;
-    let club = extract_club(row.get(6).ok_or(MisformattedRow)?)?;
+/// The registered events are given by their index in the layout's events.
+///
+/// Every malformed identity cell of the row is reported, not only the first one.
+/// Event cells with an unknown value are considered as not registered, and returned as warnings along the row.
+pub fn parse_row(
+    row: &[Data],
+    layout: &ColumnLayout,
+    import: &ImportConfiguration,
+) -> std::result::Result<(Registrant, Vec<usize>, Vec<CellError>), Vec<CellError>> {
+    let mut errors = vec![];
+    let cell = |column: usize| row.get(column).ok_or(MisformattedRow);
+
+    let id = check(&mut errors, *layout.id(), cell(*layout.id()).and_then(extract_id));
+    let first_name = check(&mut errors, *layout.first_name(), cell(*layout.first_name()).and_then(extract_first_name));
+    let last_name = check(&mut errors, *layout.last_name(), cell(*layout.last_name()).and_then(extract_last_name));
+    let birthday = check(&mut errors, *layout.birthday(), cell(*layout.birthday()).and_then(extract_birthday));
+    let age = check(&mut errors, *layout.age(), cell(*layout.age()).and_then(extract_age));
+    let gender = check(&mut errors, *layout.gender(), cell(*layout.gender()).and_then(|cell| extract_gender(cell, import.genders())));
+    let club = match layout.club() {
+        // Trailing empty cells may be trimmed from the row
+        Some(column) => check(&mut errors, *column, extract_club(row.get(*column).unwrap_or(&Data::Empty))),
+        None => Some(None),
+    };
+
+    let (Some(id), Some(first_name), Some(last_name), Some(birthday), Some(age), Some(gender), Some(club)) =
+        (id, first_name, last_name, birthday, age, gender, club)
+    else {
+        return Err(errors);
+    };
     let registrant = Registrant::new(
         id,
         first_name.clone(),
@@ -58,16 +103,26 @@ pub fn parse_row(row: &[Data]) -> Result<(Registrant, Vec<usize>)> {
         club.cloned(),
     );
 
-    let registered_events = row.iter().skip(7).enumerate()
-        .filter(|(_, registered)| match registered {
-            Data::String(value) => value.as_str() == EVENT_REGISTRATION_STRING,
-            Data::Bool(value) => *value,
-            _ => false,
+    let mut warnings = vec![];
+    let registered_events = layout
+        .events()
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| {
+            let registration = extract_registration(row.get(**column).unwrap_or(&Data::Empty), import.registrations());
+            check(&mut warnings, **column, registration).unwrap_or(false)
         })
         .map(|(index, _)| index)
         .collect();
 
-    Ok((registrant, registered_events))
+    Ok((registrant, registered_events, warnings))
+}
+
+/// Keep track of the error, if any, to report it along the row's other errors.
+fn check<T>(errors: &mut Vec<CellError>, column: usize, result: Result<T>) -> Option<T> {
+    result
+        .map_err(|error| errors.push(CellError { column, error }))
+        .ok()
 }
 
 fn extract_id(id_cell: &Data) -> Result<u16> {
@@ -96,13 +151,24 @@ fn extract_last_name(last_name_cell: &Data) -> Result<&String> {
     }
 }
 
-fn extract_birthday(birthday_cell: &Data) -> Result<String> {
-    match birthday_cell {
-        Data::String(birthday) => Ok(birthday.to_string()),
-        Data::DateTime(birthday) => Ok(birthday.to_string()),
-        Data::DateTimeIso(birthday) => Ok(birthday.to_string()),
-        _ => Err(WrongFormat("birthday has the wrong format".to_string())),
-    }
+fn extract_birthday(birthday_cell: &Data) -> Result<NaiveDate> {
+    let birthday = match birthday_cell {
+        Data::String(birthday) => parse_birthday(birthday.trim()),
+        Data::DateTime(_) | Data::DateTimeIso(_) => birthday_cell.as_date(),
+        _ => None,
+    };
+    birthday.ok_or_else(|| WrongFormat("birthday has the wrong format".to_string()))
+}
+
+fn parse_birthday(birthday: &str) -> Option<NaiveDate> {
+    BIRTHDAY_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(birthday, format).ok())
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(birthday, "%Y-%m-%dT%H:%M:%S")
+                .ok()
+                .map(|birthday| birthday.date())
+        })
 }
 
 fn extract_age(age_cell: &Data) -> Result<u8> {
@@ -117,13 +183,33 @@ fn extract_age(age_cell: &Data) -> Result<u8> {
     }
 }
 
-fn extract_gender(gender_cell: &Data) -> Result<Gender> {
+fn extract_gender(gender_cell: &Data, labels: &GenderLabels) -> Result<Gender> {
     match gender_cell {
-        Data::String(gender) => Gender::try_from(gender),
+        Data::String(gender) => Gender::parse(gender, labels),
+        Data::Empty => Ok(Gender::Unspecified),
         _ => Err(WrongFormat("gender has the wrong format".to_string())),
     }
 }
 
+fn extract_registration(registration_cell: &Data, values: &RegistrationValues) -> Result<bool> {
+    let value = match registration_cell {
+        Data::Empty => return Ok(false),
+        Data::Bool(registered) => return Ok(*registered),
+        Data::String(value) => value.trim().to_string(),
+        Data::Int(_) | Data::Float(_) => registration_cell.to_string(),
+        _ => return Err(WrongFormat("registration has the wrong format".to_string())),
+    };
+    let matches = |values: &[String]| values.iter().any(|known| known.to_lowercase() == value.to_lowercase());
+
+    if value.is_empty() || matches(values.not_registered()) {
+        Ok(false)
+    } else if matches(values.registered()) {
+        Ok(true)
+    } else {
+        Err(WrongFormat(format!("`{value}` is not a known registration value")))
+    }
+}
+
 fn extract_club(club_cell: &Data) -> Result<Option<&String>> {
     match club_cell {
         Data::String(club) => Ok(Some(club)),
@@ -135,16 +221,27 @@ fn extract_club(club_cell: &Data) -> Result<Option<&String>> {
 #[cfg(test)]
 mod tests {
     mod parse_row {
+        use crate::configuration::import_configuration::{ColumnAliases, ImportConfiguration};
+        use crate::registration::column_layout::ColumnLayout;
         use crate::registration::gender::Gender;
         use crate::registration::registrant::{parse_row, Registrant};
         use calamine::Data;
+        use chrono::NaiveDate;
+
+        fn test_layout() -> ColumnLayout {
+            let headers: Vec<String> = ["Id", "First Name", "Last Name", "Birthday", "Age", "Gender", "Club", "A - All", "B - All", "C - All", "D - All"]
+                .into_iter()
+                .map(|header| header.to_string())
+                .collect();
+            ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap()
+        }
 
         #[test]
         fn success_no_event() {
             let id = 1_u16;
             let first_name = "John";
             let last_name = "Doe";
-            let birthday = "2010-01-01";
+            let birthday = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
             let age = 15;
             let gender = "Male";
             let club = "This is a club";
@@ -153,7 +250,7 @@ mod tests {
                 id,
                 first_name.to_string(),
                 last_name.to_string(),
-                birthday.to_string(),
+                birthday,
                 age as u8,
                 Gender::Male,
                 Some(club.to_string()),
@@ -163,13 +260,13 @@ mod tests {
                 Data::Float(id as f64),
                 Data::String(first_name.to_string()),
                 Data::String(last_name.to_string()),
-                Data::String(birthday.to_string()),
+                Data::String(birthday.format("%d.%m.%Y").to_string()),
                 Data::Float(age as f64),
                 Data::String(gender.to_string()),
                 Data::String(club.to_string()),
             ];
 
-            let (registrant, registered_events) = parse_row(&row).unwrap();
+            let (registrant, registered_events, _) = parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
 
             assert_eq!(expected_registrant, registrant);
             assert_eq!(Vec::<usize>::new(), registered_events);
@@ -180,7 +277,7 @@ mod tests {
             let id = 1_u16;
             let first_name = "John";
             let last_name = "Doe";
-            let birthday = "2010-01-01";
+            let birthday = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
             let age = 15;
             let gender = "Male";
             let club = "This is a club";
@@ -189,7 +286,7 @@ mod tests {
                 id,
                 first_name.to_string(),
                 last_name.to_string(),
-                birthday.to_string(),
+                birthday,
                 age as u8,
                 Gender::Male,
                 Some(club.to_string()),
@@ -199,7 +296,7 @@ mod tests {
                 Data::Float(id as f64),
                 Data::String(first_name.to_string()),
                 Data::String(last_name.to_string()),
-                Data::String(birthday.to_string()),
+                Data::String(birthday.format("%d.%m.%Y").to_string()),
                 Data::Float(age as f64),
                 Data::String(gender.to_string()),
                 Data::String(club.to_string()),
@@ -209,7 +306,7 @@ mod tests {
                 Data::String("".to_string()),
             ];
 
-            let (registrant, registered_events) = parse_row(&row).unwrap();
+            let (registrant, registered_events, _) = parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
 
             assert_eq!(expected_registrant, registrant);
             assert_eq!(Vec::<usize>::new(), registered_events);
@@ -220,7 +317,7 @@ mod tests {
             let id = 1_u16;
             let first_name = "John";
             let last_name = "Doe";
-            let birthday = "2010-01-01";
+            let birthday = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
             let age = 15;
             let gender = "Male";
             let club = "This is a club";
@@ -229,7 +326,7 @@ mod tests {
                 id,
                 first_name.to_string(),
                 last_name.to_string(),
-                birthday.to_string(),
+                birthday,
                 age as u8,
                 Gender::Male,
                 Some(club.to_string()),
@@ -239,7 +336,7 @@ mod tests {
                 Data::Float(id as f64),
                 Data::String(first_name.to_string()),
                 Data::String(last_name.to_string()),
-                Data::String(birthday.to_string()),
+                Data::String(birthday.format("%d.%m.%Y").to_string()),
                 Data::Float(age as f64),
                 Data::String(gender.to_string()),
                 Data::String(club.to_string()),
@@ -249,17 +346,94 @@ mod tests {
                 Data::String("VRAI".to_string()),
             ];
 
-            let (registrant, registered_events) = parse_row(&row).unwrap();
+            let (registrant, registered_events, _) = parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
 
             assert_eq!(expected_registration, registrant);
             assert_eq!(vec![0, 3], registered_events);
         }
 
+        #[test]
+        fn success_reordered_columns() {
+            let headers: Vec<String> = ["E-mail", "Nom", "Prénom", "A - All", "Sexe", "Club", "Date de naissance", "Âge", "Id", "B - All"]
+                .into_iter()
+                .map(|header| header.to_string())
+                .collect();
+            let layout = ColumnLayout::from_headers(&headers, &ColumnAliases::default()).unwrap();
+
+            let expected_registrant = Registrant::new(
+                1,
+                "John".to_string(),
+                "Doe".to_string(),
+                NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+                15,
+                Gender::Male,
+                None,
+            );
+
+            let row = vec![
+                Data::String("john.doe@example.com".to_string()),
+                Data::String("Doe".to_string()),
+                Data::String("John".to_string()),
+                Data::Empty,
+                Data::String("Male".to_string()),
+                Data::Empty,
+                Data::String("2010-01-01".to_string()),
+                Data::Float(15.0),
+                Data::Float(1.0),
+                Data::Bool(true),
+            ];
+
+            let (registrant, registered_events, _) = parse_row(&row, &layout, &ImportConfiguration::default()).unwrap();
+
+            assert_eq!(expected_registrant, registrant);
+            assert_eq!(vec![1], registered_events);
+        }
+
+        #[test]
+        fn success_unknown_registration_value() {
+            let row = vec![
+                Data::Float(1.0),
+                Data::String("John".to_string()),
+                Data::String("Doe".to_string()),
+                Data::String("01.01.2010".to_string()),
+                Data::Float(15.0),
+                Data::String("Male".to_string()),
+                Data::Empty,
+                Data::String("Oui".to_string()),
+                Data::String("Peut-être".to_string()),
+                Data::Float(1.0),
+                Data::String("Non".to_string()),
+            ];
+
+            let (_, registered_events, warnings) = parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
+
+            assert_eq!(vec![0, 2], registered_events);
+            let columns: Vec<usize> = warnings.iter().map(|warning| *warning.column()).collect();
+            assert_eq!(vec![8], columns);
+        }
+
+        #[test]
+        fn fail_every_malformed_cell() {
+            let row = vec![
+                Data::Float(1.0),
+                Data::String("John".to_string()),
+                Data::Empty,
+                Data::String("2010-01-01".to_string()),
+                Data::String("fifteen".to_string()),
+                Data::String("Male".to_string()),
+            ];
+
+            let errors = parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap_err();
+
+            let columns: Vec<usize> = errors.iter().map(|error| *error.column()).collect();
+            assert_eq!(vec![2, 4], columns);
+        }
+
         #[test]
         #[should_panic(expected = "MisformattedRow")]
         fn fail_empty_row() {
             let row = vec![];
-            parse_row(&row).unwrap();
+            parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
         }
 
         #[test]
@@ -268,7 +442,7 @@ mod tests {
             let id = f64::MAX;
             let first_name = "John";
             let last_name = "Doe";
-            let birthday = "2010-01-01";
+            let birthday = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
             let age = 15;
             let gender = "Male";
             let club = "This is a club";
@@ -277,7 +451,7 @@ mod tests {
                 Data::Float(id),
                 Data::String(first_name.to_string()),
                 Data::String(last_name.to_string()),
-                Data::String(birthday.to_string()),
+                Data::String(birthday.format("%d.%m.%Y").to_string()),
                 Data::Float(age as f64),
                 Data::String(gender.to_string()),
                 Data::String(club.to_string()),
@@ -287,7 +461,7 @@ mod tests {
                 Data::String("VRAI".to_string()),
             ];
 
-            parse_row(&row).unwrap();
+            parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
         }
 
         #[test]
@@ -296,7 +470,7 @@ mod tests {
             let id = 1_f64;
             let first_name = "John";
             let last_name = "Doe";
-            let birthday = "2010-01-01";
+            let birthday = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
             let age = f64::MAX;
             let gender = "Male";
             let club = "This is a club";
@@ -305,7 +479,7 @@ mod tests {
                 Data::Float(id),
                 Data::String(first_name.to_string()),
                 Data::String(last_name.to_string()),
-                Data::String(birthday.to_string()),
+                Data::String(birthday.format("%d.%m.%Y").to_string()),
                 Data::Float(age),
                 Data::String(gender.to_string()),
                 Data::String(club.to_string()),
@@ -315,7 +489,7 @@ mod tests {
                 Data::String("VRAI".to_string()),
             ];
 
-            parse_row(&row).unwrap();
+            parse_row(&row, &test_layout(), &ImportConfiguration::default()).unwrap();
         }
     }
 
@@ -412,7 +586,8 @@ mod tests {
     mod extract_birthday {
         use crate::error::ApplicationError;
         use crate::registration::registrant::extract_birthday;
-        use calamine::Data;
+        use calamine::{Data, ExcelDateTime, ExcelDateTimeType};
+        use chrono::NaiveDate;
         use parameterized::parameterized;
 
         #[test]
@@ -422,16 +597,28 @@ mod tests {
         }
 
         #[parameterized(
-            birthday_cell = { &Data::String("01.12.1980".to_string()) },
-            expected_birthday = {"01.12.1980".to_string()}
+            birthday_cell = {
+                &Data::String("01.12.1980".to_string()),
+                &Data::String("01/12/1980".to_string()),
+                &Data::String("1980-12-01".to_string()),
+                &Data::DateTime(ExcelDateTime::new(29556.0, ExcelDateTimeType::DateTime, false)),
+                &Data::DateTimeIso("1980-12-01T00:00:00".to_string())
+            },
+            expected_birthday = {
+                NaiveDate::from_ymd_opt(1980, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1980, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1980, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1980, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1980, 12, 1).unwrap()
+            }
         )]
-        fn success(birthday_cell: &Data, expected_birthday: String) {
+        fn success(birthday_cell: &Data, expected_birthday: NaiveDate) {
             let result = extract_birthday(birthday_cell).unwrap();
             assert_eq!(expected_birthday, result);
         }
 
         #[parameterized(
-            birthday_cell = { &Data::Int(i64::MAX), &Data::Float(f64::MAX), &Data::Empty },
+            birthday_cell = { &Data::Int(i64::MAX), &Data::Float(f64::MAX), &Data::Empty, &Data::String("31.02.1980".to_string()), &Data::String("1980".to_string()) },
         )]
         fn fail(birthday_cell: &Data) {
             let result = extract_birthday(birthday_cell).err().unwrap();
@@ -470,6 +657,7 @@ mod tests {
     }
 
     mod extract_gender {
+        use crate::configuration::import_configuration::GenderLabels;
         use crate::error::ApplicationError;
         use crate::registration::gender::Gender;
         use crate::registration::registrant::extract_gender;
@@ -483,11 +671,11 @@ mod tests {
         }
 
         #[parameterized(
-            gender_cell = { &Data::String(("Male").to_string()), &Data::String(("Female").to_string()) },
-            expected_gender = {Gender::Male, Gender::Female}
+            gender_cell = { &Data::String(("Male").to_string()), &Data::String(("Female").to_string()), &Data::String(("Femme").to_string()), &Data::Empty },
+            expected_gender = {Gender::Male, Gender::Female, Gender::Female, Gender::Unspecified}
         )]
         fn success(gender_cell: &Data, expected_gender: Gender) {
-            let result = extract_gender(gender_cell).unwrap();
+            let result = extract_gender(gender_cell, &GenderLabels::default()).unwrap();
             assert_eq!(expected_gender, result);
         }
 
@@ -495,7 +683,7 @@ mod tests {
             gender_cell = { &Data::Int(i64::MAX), &Data::Float(f64::MAX), &Data::String("1".to_string()) },
         )]
         fn fail(gender_cell: &Data) {
-            let result = extract_gender(gender_cell).err().unwrap();
+            let result = extract_gender(gender_cell, &GenderLabels::default()).err().unwrap();
             assert!(matches!(result, ApplicationError::WrongFormat(_)));
         }
     }
@@ -530,5 +718,68 @@ mod tests {
         }
     }
 
+    mod age_at {
+        use crate::registration::gender::Gender;
+        use crate::registration::registrant::Registrant;
+        use chrono::NaiveDate;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            date = { NaiveDate::from_ymd_opt(2025, 5, 28).unwrap(), NaiveDate::from_ymd_opt(2025, 5, 29).unwrap(), NaiveDate::from_ymd_opt(2000, 1, 1).unwrap() },
+            expected_age = { 14, 15, 0 }
+        )]
+        fn success(date: NaiveDate, expected_age: u8) {
+            let registrant = Registrant::new(
+                1,
+                "John".to_string(),
+                "Doe".to_string(),
+                NaiveDate::from_ymd_opt(2010, 5, 29).unwrap(),
+                15,
+                Gender::Male,
+                None,
+            );
+
+            assert_eq!(expected_age, registrant.age_at(date));
+        }
+    }
 
+    mod extract_registration {
+        use crate::configuration::import_configuration::RegistrationValues;
+        use crate::error::ApplicationError;
+        use crate::registration::registrant::extract_registration;
+        use calamine::Data;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            registration_cell = {
+                &Data::Empty, &Data::Bool(true), &Data::Bool(false), &Data::String("VRAI".to_string()), &Data::String("true".to_string()),
+                &Data::String(" x ".to_string()), &Data::Int(1), &Data::Float(1.0), &Data::String("".to_string()), &Data::String("FAUX".to_string()), &Data::Int(0)
+            },
+            expected_registration = { false, true, false, true, true, true, true, true, false, false, false }
+        )]
+        fn success(registration_cell: &Data, expected_registration: bool) {
+            let result = extract_registration(registration_cell, &RegistrationValues::default()).unwrap();
+            assert_eq!(expected_registration, result);
+        }
+
+        #[parameterized(
+            registration_cell = { &Data::String("Peut-être".to_string()), &Data::Int(2), &Data::Float(0.5) },
+        )]
+        fn fail(registration_cell: &Data) {
+            let result = extract_registration(registration_cell, &RegistrationValues::default()).err().unwrap();
+            assert!(matches!(result, ApplicationError::WrongFormat(_)));
+        }
+    }
 }
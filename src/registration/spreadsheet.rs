@@ -0,0 +1,217 @@
+//! Read a registration export into a range of cells, whatever its format.
+//!
+//! XLS, XLSX, XLSB and ODS workbooks are read with [calamine], CSV files with [csv].
+//! CSV files are expected in UTF-8, or in Windows-1252 as Excel saves them on French systems.
+//! The format is detected from the file's extension or, lacking a known one, from its content.
+
+use crate::error;
+use crate::error::ApplicationError;
+use crate::error::ApplicationError::{NoHeaders, NoSheet};
+use calamine::{open_workbook_auto, Data, HeaderRow, Range, Reader};
+use encoding_rs::WINDOWS_1252;
+use std::fs::read;
+use std::path::Path;
+
+/// The sheet the registrations are read from. Ignored for CSV files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SheetSelection {
+    Named(String),
+    First,
+}
+
+impl Default for SheetSelection {
+    /// The registration platform names its only sheet `Worksheet1`.
+    fn default() -> Self {
+        SheetSelection::Named("Worksheet1".to_string())
+    }
+}
+
+const CSV_DELIMITERS: [u8; 3] = [b';', b',', b'\t'];
+
+/// Read the selected sheet of the file, starting from its first non-empty row.
+/// Returns the sheet's name along with its cells; CSV files are named after the file.
+pub fn read_range(path: &Path, sheet: &SheetSelection) -> error::Result<(String, Range<Data>)> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase());
+
+    match extension.as_deref() {
+        Some("csv" | "tsv" | "txt") => read_csv(path),
+        Some("xls" | "xla" | "xlsx" | "xlsm" | "xlam" | "xlsb" | "ods") => read_workbook(path, sheet),
+        _ => read_workbook(path, sheet).or_else(|error| match error {
+            ApplicationError::Sheet(calamine::Error::Msg(_)) => read_csv(path),
+            error => Err(error),
+        }),
+    }
+}
+
+fn read_workbook(path: &Path, sheet: &SheetSelection) -> error::Result<(String, Range<Data>)> {
+    let mut workbook = open_workbook_auto(path)?;
+    workbook.with_header_row(HeaderRow::FirstNonEmptyRow);
+
+    let name = match sheet {
+        SheetSelection::Named(name) => name.clone(),
+        SheetSelection::First => workbook.sheet_names().first().cloned().ok_or(NoSheet)?,
+    };
+    let range = workbook.worksheet_range(&name)?;
+
+    Ok((name, range))
+}
+
+fn read_csv(path: &Path) -> error::Result<(String, Range<Data>)> {
+    let bytes = read(path).map_err(|source| ApplicationError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    // Windows-1252 decodes any byte, Latin-1 files included
+    let content = match String::from_utf8(bytes) {
+        Ok(content) => content,
+        Err(error) => WINDOWS_1252.decode_without_bom_handling(error.as_bytes()).0.into_owned(),
+    };
+    let delimiter = detect_delimiter(content.lines().next().unwrap_or_default());
+
+    let records = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(content.as_bytes())
+        .into_records()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let width = records.iter().map(|record| record.len()).max().unwrap_or(0);
+    if records.is_empty() || width == 0 {
+        return Err(NoHeaders);
+    }
+
+    let mut range = Range::new((0, 0), ((records.len() - 1) as u32, (width - 1) as u32));
+    for (row, record) in records.iter().enumerate() {
+        for (column, value) in record.iter().enumerate() {
+            range.set_value((row as u32, column as u32), parse_csv_cell(value));
+        }
+    }
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    Ok((name, range))
+}
+
+/// The delimiter appearing the most in the header line, `;` winning ties as French exports usually use it.
+fn detect_delimiter(header_line: &str) -> u8 {
+    CSV_DELIMITERS
+        .into_iter()
+        .rev()
+        .max_by_key(|delimiter| header_line.bytes().filter(|byte| byte == delimiter).count())
+        .unwrap_or(b';')
+}
+
+/// CSV cells are untyped: give them the type a spreadsheet would have given them.
+fn parse_csv_cell(value: &str) -> Data {
+    let value = value.trim();
+    let looks_numeric = value.chars().any(|c| c.is_ascii_digit());
+
+    if value.is_empty() {
+        Data::Empty
+    } else if let (true, Ok(value)) = (looks_numeric, value.parse::<i64>()) {
+        Data::Int(value)
+    } else if let (true, Ok(value)) = (looks_numeric, value.parse::<f64>()) {
+        Data::Float(value)
+    } else if value.eq_ignore_ascii_case("true") {
+        Data::Bool(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Data::Bool(false)
+    } else {
+        Data::String(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod read_range {
+        use crate::registration::spreadsheet::{read_range, SheetSelection};
+        use crate::test_data::get_test_asset;
+        use calamine::Data;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            asset = { "registration/registrations.xls", "registration/registrations.xlsx", "registration/registrations.ods", "registration/registrations.csv", "registration/registrations-windows-1252.csv", "registration/registrations-export" },
+        )]
+        fn success(asset: &str) {
+            let (_, range) = read_range(&get_test_asset(asset), &SheetSelection::default()).unwrap();
+
+            assert_eq!((51, 38), range.get_size());
+            assert_eq!(Some("Id".to_string()), range.headers().and_then(|headers| headers.first().cloned()));
+        }
+
+        #[test]
+        fn success_windows_1252() {
+            let (_, range) = read_range(&get_test_asset("registration/registrations-windows-1252.csv"), &SheetSelection::default()).unwrap();
+
+            assert_eq!(Some(&Data::String("Bègue".to_string())), range.get_value((1, 2)));
+            assert_eq!(Some(&Data::String("intérieur Club".to_string())), range.get_value((2, 6)));
+        }
+
+        #[test]
+        fn success_first_sheet() {
+            let path = get_test_asset("registration/wrong_sheet_name.xls");
+            let (name, range) = read_range(&path, &SheetSelection::First).unwrap();
+
+            assert_eq!("AnotherSheet1", name);
+            assert_eq!(51, range.height());
+        }
+
+        #[test]
+        #[should_panic(expected = "WorksheetNotFound")]
+        fn fail_sheet_not_found() {
+            let path = get_test_asset("registration/registrations.xlsx");
+            read_range(&path, &SheetSelection::Named("Inscriptions".to_string())).unwrap();
+        }
+    }
+
+    mod detect_delimiter {
+        use crate::registration::spreadsheet::detect_delimiter;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            header_line = { "Id;First Name;Last Name", "Id,First Name,Last Name", "Id\tFirst Name\tLast Name", "Id" },
+            expected_delimiter = { b';', b',', b'\t', b';' }
+        )]
+        fn success(header_line: &str, expected_delimiter: u8) {
+            assert_eq!(expected_delimiter, detect_delimiter(header_line));
+        }
+    }
+
+    mod parse_csv_cell {
+        use crate::registration::spreadsheet::parse_csv_cell;
+        use calamine::Data;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            value = { "", "12", "1.5", "TRUE", "false", "VRAI", "31.12.1966", "Nan" },
+            expected_cell = { Data::Empty, Data::Int(12), Data::Float(1.5), Data::Bool(true), Data::Bool(false), Data::String("VRAI".to_string()), Data::String("31.12.1966".to_string()), Data::String("Nan".to_string()) }
+        )]
+        fn success(value: &str, expected_cell: Data) {
+            assert_eq!(expected_cell, parse_csv_cell(value));
+        }
+    }
+}
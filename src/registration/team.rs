@@ -0,0 +1,279 @@
+//! Teams, pairs and groups registrants have entered an event with.
+
+use crate::registration::column_layout::ColumnLayout;
+use crate::registration::registrant::Registrant;
+use calamine::Data;
+use derive_getters::Getters;
+use std::collections::HashMap;
+
+/// What a detail column, such as `Basket - Nom de l'équipe`, tells about a registration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailKind {
+    Captain,
+    Team,
+    Group,
+    Partner,
+}
+
+impl DetailKind {
+    const SUFFIXES: [(&'static str, DetailKind); 4] = [
+        ("Nom du capitaine", DetailKind::Captain),
+        ("Nom de l'équipe", DetailKind::Team),
+        ("Nom du groupe", DetailKind::Group),
+        ("Nom du partenaire", DetailKind::Partner),
+    ];
+
+    /// Split a detail header into the discipline it details and the kind of detail,
+    /// e.g. `Paire - Nom du partenaire` into `Paire` and [DetailKind::Partner].
+    pub fn from_header(header: &str) -> Option<(&str, DetailKind)> {
+        let (discipline, suffix) = header.rsplit_once(" - ")?;
+        Self::SUFFIXES
+            .iter()
+            .find(|(expected, _)| *expected == suffix.trim())
+            .map(|(_, kind)| (discipline.trim(), *kind))
+    }
+}
+
+/// Details a registrant gave about the team, pair or group they entered an event with.
+#[derive(Debug, Getters, Clone, Default, PartialEq, Eq)]
+pub struct TeamEntry {
+    /// Refers to the event's index in [crate::registration::convention::Convention::events].
+    event: usize,
+    captain: Option<String>,
+    team: Option<String>,
+    group: Option<String>,
+    partner: Option<String>,
+}
+
+impl TeamEntry {
+    pub fn new(event: usize) -> Self {
+        Self {
+            event,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_detail(mut self, kind: DetailKind, value: String) -> Self {
+        let field = match kind {
+            DetailKind::Captain => &mut self.captain,
+            DetailKind::Team => &mut self.team,
+            DetailKind::Group => &mut self.group,
+            DetailKind::Partner => &mut self.partner,
+        };
+        *field = Some(value);
+        self
+    }
+
+    /// Same details, about another event.
+    /// Used when events are normalized and their indexes change.
+    pub fn with_event(mut self, event: usize) -> Self {
+        self.event = event;
+        self
+    }
+
+    fn name(&self) -> Option<&String> {
+        self.team.as_ref().or(self.group.as_ref())
+    }
+}
+
+/// A team, pair or group of an event, reconstructed from the details its members gave.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct Team {
+    /// Team or group name if any was given, otherwise the captain's or the members' names.
+    name: String,
+    members: Vec<Registrant>,
+    /// Captains and partners named by the members, but not registered to the event.
+    unregistered_members: Vec<String>,
+}
+
+/// Read the detail columns of a row, ignoring empty cells.
+/// Entries are sorted by event, one per event with at least one detail.
+pub fn parse_team_entries(row: &[Data], layout: &ColumnLayout) -> Vec<TeamEntry> {
+    let mut entries: Vec<TeamEntry> = vec![];
+    for (column, event, kind) in layout.details() {
+        let value = row.get(*column).map(|cell| cell.to_string()).unwrap_or_default();
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+
+        match entries.iter().position(|entry| entry.event == *event) {
+            Some(position) => entries[position] = entries[position].clone().with_detail(*kind, value.to_string()),
+            None => entries.push(TeamEntry::new(*event).with_detail(*kind, value.to_string())),
+        }
+    }
+    entries.sort_by_key(|entry| entry.event);
+
+    entries
+}
+
+/// Gather the participants of an event into teams.
+///
+/// Participants sharing a team or group name are in the same team,
+/// as are participants naming each other as captain or partner, even if only one of them did.
+/// Participants who gave no details and were named by nobody form a team on their own.
+pub fn build_teams(participants: &[(&Registrant, Option<&TeamEntry>)]) -> Vec<Team> {
+    let mut parents: Vec<usize> = (0..participants.len()).collect();
+    let mut unregistered: Vec<Vec<String>> = vec![vec![]; participants.len()];
+
+    let mut by_person: HashMap<String, usize> = HashMap::new();
+    for (index, (registrant, _)) in participants.iter().enumerate() {
+        by_person.entry(normalize(&format!("{} {}", registrant.first_name(), registrant.last_name()))).or_insert(index);
+        by_person.entry(normalize(&format!("{} {}", registrant.last_name(), registrant.first_name()))).or_insert(index);
+    }
+
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+    for (index, (_, entry)) in participants.iter().enumerate() {
+        let Some(entry) = entry else { continue };
+        for name in [&entry.team, &entry.group].into_iter().flatten() {
+            match by_name.get(&normalize(name)) {
+                Some(other) => union(&mut parents, index, *other),
+                None => {
+                    by_name.insert(normalize(name), index);
+                }
+            }
+        }
+        for person in [&entry.captain, &entry.partner].into_iter().flatten() {
+            match by_person.get(&normalize(person)) {
+                Some(other) => union(&mut parents, index, *other),
+                None => unregistered[index].push(person.clone()),
+            }
+        }
+    }
+
+    let mut teams: Vec<(Vec<usize>, Vec<String>)> = vec![];
+    let mut team_by_root: HashMap<usize, usize> = HashMap::new();
+    for (index, persons) in unregistered.iter().enumerate() {
+        let root = find(&mut parents, index);
+        let team = *team_by_root.entry(root).or_insert_with(|| {
+            teams.push((vec![], vec![]));
+            teams.len() - 1
+        });
+        teams[team].0.push(index);
+        for person in persons {
+            if !teams[team].1.iter().any(|known| normalize(known) == normalize(person)) {
+                teams[team].1.push(person.clone());
+            }
+        }
+    }
+
+    teams
+        .into_iter()
+        .map(|(members, unregistered_members)| {
+            let entries: Vec<&TeamEntry> = members.iter().filter_map(|member| participants[*member].1).collect();
+            let name = entries
+                .iter()
+                .find_map(|entry| entry.name())
+                .or_else(|| entries.iter().find_map(|entry| entry.captain.as_ref()))
+                .cloned()
+                .unwrap_or_else(|| {
+                    members
+                        .iter()
+                        .map(|member| participants[*member].0)
+                        .map(|registrant| format!("{} {}", registrant.first_name(), registrant.last_name()))
+                        .chain(unregistered_members.iter().cloned())
+                        .collect::<Vec<_>>()
+                        .join(" & ")
+                });
+
+            Team {
+                name,
+                members: members.iter().map(|member| participants[*member].0.clone()).collect(),
+                unregistered_members,
+            }
+        })
+        .collect()
+}
+
+/// Names are typed by hand: case and spacing differ from one registrant to another.
+fn normalize(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn find(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[index] = root;
+    root
+}
+
+fn union(parents: &mut [usize], first: usize, second: usize) {
+    let first = find(parents, first);
+    let second = find(parents, second);
+    // Keeping the earliest participant as root keeps teams in the order of their first member
+    parents[first.max(second)] = first.min(second);
+}
+
+#[cfg(test)]
+mod tests {
+    mod from_header {
+        use crate::registration::team::DetailKind;
+
+        #[test]
+        fn success() {
+            assert_eq!(Some(("Paire", DetailKind::Partner)), DetailKind::from_header("Paire - Nom du partenaire"));
+            assert_eq!(Some(("Basket A", DetailKind::Team)), DetailKind::from_header("Basket A - Nom de l'équipe"));
+        }
+
+        #[test]
+        fn success_not_a_detail() {
+            assert_eq!(None, DetailKind::from_header("Basket A - All"));
+            assert_eq!(None, DetailKind::from_header("Club"));
+        }
+    }
+
+    mod build_teams {
+        use crate::registration::gender::Gender;
+        use crate::registration::registrant::Registrant;
+        use crate::registration::team::{build_teams, DetailKind, TeamEntry};
+        use crate::test_data::birthday;
+
+        fn registrant(id: u16, first_name: &str, last_name: &str) -> Registrant {
+            Registrant::new(id, first_name.to_string(), last_name.to_string(), birthday("01.01.2000"), 25, Gender::Female, None)
+        }
+
+        fn entry(kind: DetailKind, value: &str) -> TeamEntry {
+            TeamEntry::new(0).with_detail(kind, value.to_string())
+        }
+
+        #[test]
+        fn success_partner_named_once() {
+            let (alice, bob, carol) = (registrant(1, "Alice", "Martin"), registrant(2, "Bob", "Durand"), registrant(3, "Carol", "Petit"));
+            let alice_entry = entry(DetailKind::Partner, "bob  DURAND");
+
+            let teams = build_teams(&[(&alice, Some(&alice_entry)), (&bob, None), (&carol, None)]);
+
+            assert_eq!(2, teams.len());
+            assert_eq!("Alice Martin & Bob Durand", teams[0].name());
+            assert_eq!(&vec![alice, bob], teams[0].members());
+            assert_eq!(&vec![carol], teams[1].members());
+        }
+
+        #[test]
+        fn success_team_name_and_captain() {
+            let (alice, bob, carol) = (registrant(1, "Alice", "Martin"), registrant(2, "Bob", "Durand"), registrant(3, "Carol", "Petit"));
+            let alice_entry = entry(DetailKind::Team, "Les Monocycles");
+            let bob_entry = entry(DetailKind::Team, "les monocycles");
+            let carol_entry = entry(DetailKind::Captain, "Martin Alice");
+
+            let teams = build_teams(&[(&alice, Some(&alice_entry)), (&bob, Some(&bob_entry)), (&carol, Some(&carol_entry))]);
+
+            assert_eq!(1, teams.len());
+            assert_eq!("Les Monocycles", teams[0].name());
+            assert_eq!(3, teams[0].members().len());
+        }
+
+        #[test]
+        fn success_unregistered_partner() {
+            let alice = registrant(1, "Alice", "Martin");
+            let alice_entry = entry(DetailKind::Partner, "Zoé Lambert");
+
+            let teams = build_teams(&[(&alice, Some(&alice_entry))]);
+
+            assert_eq!("Alice Martin & Zoé Lambert", teams[0].name());
+            assert_eq!(&vec!["Zoé Lambert".to_string()], teams[0].unregistered_members());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Age groups registrants are gathered into.
+
+use derive_getters::Getters;
+
+/// Consecutive age brackets, each starting at one of the lower bounds and ending before the next one.
+/// The last bracket is open-ended.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct AgeBrackets {
+    lower_bounds: Vec<u8>,
+}
+
+impl Default for AgeBrackets {
+    fn default() -> Self {
+        // IUF age groups
+        Self::new(vec![0, 11, 13, 15, 17, 19, 30])
+    }
+}
+
+impl AgeBrackets {
+    /// Bounds are sorted and deduplicated. The first bracket always starts at 0.
+    pub fn new(mut lower_bounds: Vec<u8>) -> Self {
+        lower_bounds.push(0);
+        lower_bounds.sort();
+        lower_bounds.dedup();
+        Self { lower_bounds }
+    }
+
+    pub fn count(&self) -> usize {
+        self.lower_bounds.len()
+    }
+
+    /// Index of the bracket given age belongs to.
+    pub fn bracket(&self, age: u8) -> usize {
+        self.lower_bounds
+            .iter()
+            .rposition(|lower_bound| *lower_bound <= age)
+            .unwrap_or_default()
+    }
+
+    /// Label of a bracket, such as `10-12`, or `50+` for the last one.
+    pub fn label(&self, bracket: usize) -> String {
+        match self.lower_bounds.get(bracket + 1) {
+            Some(next) if *next == self.lower_bounds[bracket] + 1 => self.lower_bounds[bracket].to_string(),
+            Some(next) => format!("{}-{}", self.lower_bounds[bracket], next - 1),
+            None => format!("{}+", self.lower_bounds[bracket]),
+        }
+    }
+
+    /// Labels of every bracket, from the youngest to the oldest.
+    pub fn labels(&self) -> Vec<String> {
+        (0..self.count()).map(|bracket| self.label(bracket)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod bracket {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use parameterized::parameterized;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(
+            age = { 0, 10, 11, 12, 13, 29, 30, 99 },
+            bracket = { 0, 0, 1, 1, 2, 5, 6, 6 },
+        )]
+        fn success(age: u8, bracket: usize) {
+            assert_eq!(bracket, AgeBrackets::default().bracket(age));
+        }
+    }
+
+    mod labels {
+        use crate::statistics::age_brackets::AgeBrackets;
+
+        #[test]
+        fn success() {
+            assert_eq!(vec!["0-10", "11-12", "13-14", "15-16", "17-18", "19-29", "30+"], AgeBrackets::default().labels());
+        }
+
+        #[test]
+        fn success_single_age_and_missing_zero() {
+            assert_eq!(vec!["0-17", "18", "19+"], AgeBrackets::new(vec![19, 18]).labels());
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! How old the participants of each event are.
+
+use super::error::Result;
+use crate::configuration::events_mapping::canonical_event_category;
+use crate::registration::convention::Convention;
+use crate::registration::registrant::Registrant;
+use crate::statistics::age_brackets::AgeBrackets;
+use crate::statistics::error::DrawingError;
+use derive_getters::Getters;
+use plotters::prelude::{FontTransform::*, *};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Participants of each age bracket, in the order of [AgeBrackets::labels].
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct AgeDistribution {
+    /// Sorted by event name.
+    events: Vec<(String, Vec<usize>)>,
+    /// Only for conventions normalized to canonical events, sorted by category.
+    /// A rider entering several events of a category is counted once.
+    categories: Vec<(String, Vec<usize>)>,
+}
+
+/// Gather the participants of each event, and of each category, into age brackets.
+pub fn compute_age_distribution(convention: &Convention, brackets: &AgeBrackets) -> AgeDistribution {
+    let count = |participants: &mut dyn Iterator<Item = &Registrant>| {
+        participants.fold(vec![0; brackets.count()], |mut counts, registrant| {
+            counts[brackets.bracket(*registrant.age())] += 1;
+            counts
+        })
+    };
+
+    let mut events: Vec<(String, Vec<usize>)> = convention
+        .events()
+        .iter()
+        .map(|event| {
+            let participants = &convention.participants_by_event()[*event.index()];
+            (event.name().clone(), count(&mut participants.iter()))
+        })
+        .collect();
+    events.sort();
+
+    let mut participants_by_category: BTreeMap<&str, HashSet<&Registrant>> = BTreeMap::new();
+    for event in convention.events() {
+        if let Some(category) = canonical_event_category(event.name()) {
+            participants_by_category
+                .entry(category)
+                .or_default()
+                .extend(convention.participants_by_event()[*event.index()].iter());
+        }
+    }
+    let categories = participants_by_category
+        .into_iter()
+        .map(|(category, participants)| (category.to_string(), count(&mut participants.into_iter())))
+        .collect();
+
+    AgeDistribution { events, categories }
+}
+
+/// Export the distribution as a semicolon separated table, one column per age bracket:
+/// one line per event, followed by one line per category.
+pub fn generate_csv_file(distribution: &AgeDistribution, brackets: &AgeBrackets) -> String {
+    let mut content = format!(";{}", brackets.labels().join(";"));
+    for (name, counts) in distribution.events.iter().chain(distribution.categories.iter()) {
+        let cells = counts.iter().map(usize::to_string).collect::<Vec<_>>().join(";");
+        content = format!("{content}\n{name};{cells}");
+    }
+
+    content
+}
+
+/// Draw a histogram with a bar per event, stacking its participants' age brackets.
+///
+/// Once generated, the graph is saved as `ages.png` in given folder.
+pub fn draw_and_export_graph(distribution: &AgeDistribution, brackets: &AgeBrackets, folder: &Path) -> Result<()> {
+    let file = folder.join("ages.png");
+    let drawing_area = BitMapBackend::new(&file, (2048, 2048)).into_drawing_area();
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let events = &distribution.events;
+    let longest_event_name_length = events.iter().map(|(name, _)| name.len()).max().unwrap_or(10);
+    let max_participants_count = events
+        .iter()
+        .map(|(_, counts)| counts.iter().sum::<usize>())
+        .max()
+        .unwrap_or(10);
+    let upper_y_bound = (((max_participants_count + 10) / 10) * 10) as i32;
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin_bottom((longest_event_name_length as u32) * 9)
+        .set_label_area_size(LabelAreaPosition::Left, 40)
+        .set_label_area_size(LabelAreaPosition::Right, 40)
+        .caption("Répartition par âge par épreuve", ("sans-serif", 40))
+        .build_cartesian_2d(0.0..events.len().max(1) as f32, 0..upper_y_bound)
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .disable_x_axis()
+        .disable_x_mesh()
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    for (bracket, label) in brackets.labels().into_iter().enumerate() {
+        let color = Palette99::pick(bracket).to_rgba();
+        chart
+            .draw_series(events.iter().enumerate().map(|(x, (_, counts))| {
+                let bottom = counts[..bracket].iter().sum::<usize>() as i32;
+                let top = bottom + counts[bracket] as i32;
+                Rectangle::new([(x as f32 + 0.1, bottom), (x as f32 + 0.9, top)], color.filled())
+            }))
+            .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?
+            .label(label)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 8), (x + 16, y + 8)], color.filled()));
+    }
+
+    let font_desc = FontDesc::new(FontFamily::SansSerif, 16_f64, FontStyle::Normal).transform(Rotate90);
+    chart
+        .draw_series(
+            events
+                .iter()
+                .enumerate()
+                .map(|(x, (name, _))| Text::new(format!("  {name}"), (x as f32 + 0.4, -1), font_desc.clone())),
+        )
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperRight)
+        .label_font(("sans-serif", 20))
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod compute_age_distribution {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::age_distribution::compute_age_distribution;
+        use crate::test_data::{get_test_convention, get_test_normalized_convention};
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let distribution = compute_age_distribution(&convention, &AgeBrackets::default());
+
+            assert_eq!(convention.events().len(), distribution.events().len());
+            let (name, counts) = &distribution.events()[0];
+            assert_eq!("10 kilomètres - Illimité", name);
+            assert_eq!(convention.participants_by_event()[15].len(), counts.iter().sum::<usize>());
+            assert!(distribution.categories().is_empty());
+        }
+
+        #[test]
+        fn success_brackets() {
+            let convention = get_test_convention();
+            let brackets = AgeBrackets::new(vec![18, 60]);
+
+            let distribution = compute_age_distribution(&convention, &brackets);
+
+            let (_, counts) = distribution.events().iter().find(|(name, _)| name == "Trial - All").unwrap();
+            let ages: Vec<u8> = convention.participants_by_event()[19].iter().map(|registrant| *registrant.age()).collect();
+            assert_eq!(
+                &vec![
+                    ages.iter().filter(|age| **age < 18).count(),
+                    ages.iter().filter(|age| (18..60).contains(*age)).count(),
+                    ages.iter().filter(|age| **age >= 60).count(),
+                ],
+                counts
+            );
+        }
+
+        #[test]
+        fn success_categories() {
+            let convention = get_test_normalized_convention();
+
+            let distribution = compute_age_distribution(&convention, &AgeBrackets::default());
+
+            let (_, counts) = distribution.categories().iter().find(|(name, _)| name == "courses-sur-route").unwrap();
+            let riders = convention
+                .registrations()
+                .iter()
+                .filter(|(_, events)| events.iter().any(|event| convention.events()[*event].name().starts_with("courses-sur-route/")))
+                .count();
+            assert_eq!(riders, counts.iter().sum::<usize>());
+        }
+    }
+
+    mod generate_csv_file {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::age_distribution::{generate_csv_file, AgeDistribution};
+
+        #[test]
+        fn success() {
+            let distribution = AgeDistribution {
+                events: vec![("Flat - All".to_string(), vec![1, 2, 3])],
+                categories: vec![("urbain".to_string(), vec![4, 5, 6])],
+            };
+
+            assert_eq!(";0-17;18-59;60+\nFlat - All;1;2;3\nurbain;4;5;6", generate_csv_file(&distribution, &AgeBrackets::new(vec![18, 60])));
+        }
+    }
+
+    mod draw_and_export_graph {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::age_distribution::{compute_age_distribution, draw_and_export_graph};
+        use crate::test_data::get_test_convention;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+
+        #[test]
+        fn success() {
+            let folder = temp_dir().join("age-distribution");
+            create_dir_all(&folder).unwrap();
+            let brackets = AgeBrackets::default();
+            let distribution = compute_age_distribution(&get_test_convention(), &brackets);
+
+            draw_and_export_graph(&distribution, &brackets, &folder).unwrap();
+
+            assert!(folder.join("ages.png").exists());
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Age pyramid of the registrants, females on the left and males on the right.
+
+use super::error::Result;
+use crate::configuration::events_mapping::canonical_event_category;
+use crate::error;
+use crate::error::ApplicationError::{AmbiguousEvent, UnknownEvent};
+use crate::registration::convention::Convention;
+use crate::registration::gender::Gender;
+use crate::registration::registrant::Registrant;
+use crate::statistics::age_brackets::AgeBrackets;
+use crate::statistics::error::DrawingError;
+use crate::statistics::gender_repartition::gender_color;
+use derive_getters::Getters;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Registrants whose ages are drawn.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PyramidScope {
+    /// Every registrant of the convention.
+    #[default]
+    Convention,
+    /// Participants of the event, or canonical event, with given name.
+    /// Raw headers shared by several events, e.g. `Parcours IUF - All`, are rejected as ambiguous.
+    Event(String),
+    /// Participants of any canonical event of the category.
+    Category(String),
+}
+
+/// Females and males of each age bracket, in the order of [AgeBrackets::labels].
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct AgePyramid {
+    females: Vec<usize>,
+    males: Vec<usize>,
+    /// Non-binary and unspecified registrants, who have no side of the pyramid.
+    others: usize,
+}
+
+/// Count the females and males of each age bracket among the registrants in scope.
+pub fn compute_age_pyramid(
+    convention: &Convention,
+    brackets: &AgeBrackets,
+    scope: &PyramidScope,
+) -> error::Result<AgePyramid> {
+    let registrants: Vec<&Registrant> = match scope {
+        PyramidScope::Convention => convention.registrations().iter().map(|(registrant, _)| registrant).collect(),
+        PyramidScope::Event(name) => match matching_events(convention, name, |event| event == name)?.as_slice() {
+            [event] => participants_of(convention, &[*event]),
+            _ => return Err(AmbiguousEvent(name.clone())),
+        },
+        PyramidScope::Category(category) => participants_of(
+            convention,
+            &matching_events(convention, category, |event| canonical_event_category(event) == Some(category))?,
+        ),
+    };
+
+    let mut pyramid = AgePyramid {
+        females: vec![0; brackets.count()],
+        males: vec![0; brackets.count()],
+        others: 0,
+    };
+    for registrant in registrants {
+        let bracket = brackets.bracket(*registrant.age());
+        match registrant.gender() {
+            Gender::Female => pyramid.females[bracket] += 1,
+            Gender::Male => pyramid.males[bracket] += 1,
+            Gender::NonBinary | Gender::Unspecified => pyramid.others += 1,
+        }
+    }
+
+    Ok(pyramid)
+}
+
+/// Indexes of the events whose names match, at least one.
+fn matching_events(convention: &Convention, name: &str, matches: impl Fn(&str) -> bool) -> error::Result<Vec<usize>> {
+    let events: Vec<usize> = convention
+        .events()
+        .iter()
+        .filter(|event| matches(event.name()))
+        .map(|event| *event.index())
+        .collect();
+    match events.is_empty() {
+        true => Err(UnknownEvent(name.to_string())),
+        false => Ok(events),
+    }
+}
+
+/// Participants of the events, each counted once.
+fn participants_of<'a>(convention: &'a Convention, events: &[usize]) -> Vec<&'a Registrant> {
+    let mut seen = HashSet::new();
+    events
+        .iter()
+        .flat_map(|event| convention.participants_by_event()[*event].iter())
+        .filter(|registrant| seen.insert(*registrant))
+        .collect()
+}
+
+/// Draw the age pyramid of the registrants in scope.
+///
+/// Once generated, the graph is saved to `pyramid.png` in given folder.
+pub fn draw_and_export_pyramid(
+    pyramid: &AgePyramid,
+    brackets: &AgeBrackets,
+    caption: &str,
+    folder: &Path,
+) -> Result<()> {
+    let file = folder.join("pyramid.png");
+    let drawing_area = draw_pyramid(pyramid, brackets, caption, &file)?;
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
+
+fn draw_pyramid<'a>(
+    pyramid: &AgePyramid,
+    brackets: &AgeBrackets,
+    caption: &str,
+    file: &'a PathBuf,
+) -> Result<DrawingArea<BitMapBackend<'a>, Shift>> {
+    let drawing_area = BitMapBackend::new(file, (2048, 2048)).into_drawing_area();
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let max_count = pyramid
+        .females
+        .iter()
+        .chain(pyramid.males.iter())
+        .copied()
+        .max()
+        .unwrap_or_default();
+    let x_bound = (((max_count + 5) / 5) * 5) as i32;
+    let caption = match pyramid.others {
+        0 => caption.to_string(),
+        others => format!("{caption} ({others} autres genres)"),
+    };
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(40)
+        .caption(caption, ("sans-serif", 40))
+        .set_label_area_size(LabelAreaPosition::Left, 100)
+        .set_label_area_size(LabelAreaPosition::Bottom, 60)
+        .build_cartesian_2d(-x_bound..x_bound, (0..brackets.count() as i32).into_segmented())
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .x_label_formatter(&|count| count.abs().to_string())
+        .y_label_formatter(&|bracket| match bracket {
+            SegmentValue::CenterOf(bracket) if (*bracket as usize) < brackets.count() => brackets.label(*bracket as usize),
+            _ => String::new(),
+        })
+        .label_style(("sans-serif", 24))
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    for (gender, side, counts) in [(Gender::Female, -1, &pyramid.females), (Gender::Male, 1, &pyramid.males)] {
+        let color = gender_color(&gender);
+        chart
+            .draw_series(counts.iter().enumerate().map(|(bracket, count)| {
+                let mut bar = Rectangle::new(
+                    [
+                        (0, SegmentValue::Exact(bracket as i32)),
+                        (side * *count as i32, SegmentValue::Exact(bracket as i32 + 1)),
+                    ],
+                    color.filled(),
+                );
+                bar.set_margin(2, 2, 0, 0);
+                bar
+            }))
+            .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+    }
+
+    Ok(drawing_area)
+}
+
+#[cfg(test)]
+mod tests {
+    mod compute_age_pyramid {
+        use crate::error::ApplicationError;
+        use crate::registration::gender::Gender;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::age_pyramid::{compute_age_pyramid, PyramidScope};
+        use crate::test_data::{get_test_convention, get_test_normalized_convention};
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let pyramid = compute_age_pyramid(&convention, &AgeBrackets::new(vec![18, 60]), &PyramidScope::Convention).unwrap();
+
+            let females = convention
+                .registrations()
+                .iter()
+                .filter(|(registrant, _)| *registrant.gender() == Gender::Female)
+                .count();
+            assert_eq!(females, pyramid.females().iter().sum::<usize>());
+            assert_eq!(convention.registrations().len() - females, pyramid.males().iter().sum::<usize>());
+            assert_eq!(0, *pyramid.others());
+        }
+
+        #[test]
+        fn success_event() {
+            let convention = get_test_convention();
+
+            let pyramid = compute_age_pyramid(&convention, &AgeBrackets::default(), &PyramidScope::Event("Basket - All".to_string())).unwrap();
+
+            assert_eq!(&vec![1, 0, 0, 0, 0, 2, 5], pyramid.females());
+            assert_eq!(&vec![0, 0, 0, 1, 0, 1, 1], pyramid.males());
+        }
+
+        #[test]
+        fn success_category() {
+            let convention = get_test_normalized_convention();
+
+            let pyramid = compute_age_pyramid(&convention, &AgeBrackets::default(), &PyramidScope::Category("courses-sur-route".to_string())).unwrap();
+
+            let riders = convention
+                .registrations()
+                .iter()
+                .filter(|(_, events)| events.iter().any(|event| convention.events()[*event].name().starts_with("courses-sur-route/")))
+                .count();
+            assert_eq!(riders, pyramid.females().iter().chain(pyramid.males().iter()).sum::<usize>());
+        }
+
+        #[test]
+        fn fail_unknown_event() {
+            let convention = get_test_convention();
+
+            let error = compute_age_pyramid(&convention, &AgeBrackets::default(), &PyramidScope::Category("athletisme".to_string())).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::UnknownEvent(name) if name == "athletisme"));
+        }
+
+        #[test]
+        fn fail_ambiguous_event() {
+            let convention = get_test_convention();
+
+            let error = compute_age_pyramid(&convention, &AgeBrackets::default(), &PyramidScope::Event("Parcours IUF - All".to_string())).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::AmbiguousEvent(name) if name == "Parcours IUF - All"));
+        }
+    }
+
+    mod draw_and_export_pyramid {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::age_pyramid::{compute_age_pyramid, draw_and_export_pyramid, PyramidScope};
+        use crate::test_data::get_test_convention;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+
+        #[test]
+        fn success() {
+            let folder = temp_dir().join("age-pyramid");
+            create_dir_all(&folder).unwrap();
+            let brackets = AgeBrackets::default();
+            let pyramid = compute_age_pyramid(&get_test_convention(), &brackets, &PyramidScope::Convention).unwrap();
+
+            draw_and_export_pyramid(&pyramid, &brackets, "Pyramide des âges", &folder).unwrap();
+
+            assert!(folder.join("pyramid.png").exists());
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Which clubs riders come from.
+
+use super::error::Result;
+use crate::configuration::events_mapping::canonical_event_category;
+use crate::registration::convention::Convention;
+use crate::registration::gender::Gender;
+use crate::registration::registrant::Registrant;
+use crate::statistics::age_brackets::AgeBrackets;
+use crate::statistics::error::DrawingError;
+use crate::statistics::gender_repartition::gender_color;
+use derive_getters::Getters;
+use plotters::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// A registrant and the events they entered, as in [Convention::registrations].
+type Registration = (Registrant, Vec<usize>);
+
+/// Riders of a club, or riders without club.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct ClubStatistics {
+    /// As typed by the club's first registrant.
+    name: String,
+    riders: usize,
+    females: usize,
+    males: usize,
+    /// Non-binary and unspecified riders.
+    others: usize,
+    mean_age: f32,
+    /// Riders of each age bracket, in the order of [AgeBrackets::labels].
+    ages: Vec<usize>,
+    /// Distinct events the club's riders entered.
+    events: usize,
+    /// Distinct categories of these events. Only canonical events belong to a category.
+    categories: usize,
+}
+
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct ClubReport {
+    registrants: usize,
+    /// Sorted from the club with the most riders to the one with the fewest, then by name.
+    clubs: Vec<ClubStatistics>,
+    /// Riders who gave no club, if any.
+    without_club: Option<ClubStatistics>,
+}
+
+/// Gather the registrants of the convention by club.
+/// Club names are typed by hand: case and spacing are not taken into account.
+pub fn compute_club_report(convention: &Convention, brackets: &AgeBrackets) -> ClubReport {
+    let mut clubs: Vec<(String, Vec<&Registration>)> = vec![];
+    let mut club_by_name: HashMap<String, usize> = HashMap::new();
+    let mut without_club = vec![];
+    for registration in convention.registrations() {
+        let club = registration.0.club().as_deref().map(str::trim).filter(|club| !club.is_empty());
+        match club {
+            Some(club) => {
+                let index = *club_by_name.entry(normalize(club)).or_insert_with(|| {
+                    clubs.push((club.to_string(), vec![]));
+                    clubs.len() - 1
+                });
+                clubs[index].1.push(registration);
+            }
+            None => without_club.push(registration),
+        }
+    }
+
+    let mut clubs: Vec<ClubStatistics> = clubs
+        .into_iter()
+        .map(|(name, registrations)| summarize(convention, brackets, name, &registrations))
+        .collect();
+    clubs.sort_by(|first, second| second.riders.cmp(&first.riders).then_with(|| first.name.cmp(&second.name)));
+
+    ClubReport {
+        registrants: convention.registrations().len(),
+        clubs,
+        without_club: (!without_club.is_empty())
+            .then(|| summarize(convention, brackets, String::new(), &without_club)),
+    }
+}
+
+fn summarize(
+    convention: &Convention,
+    brackets: &AgeBrackets,
+    name: String,
+    registrations: &[&Registration],
+) -> ClubStatistics {
+    let count = |gender: Gender| registrations.iter().filter(|(registrant, _)| *registrant.gender() == gender).count();
+    let (females, males) = (count(Gender::Female), count(Gender::Male));
+
+    let mut ages = vec![0; brackets.count()];
+    for (registrant, _) in registrations {
+        ages[brackets.bracket(*registrant.age())] += 1;
+    }
+
+    let events: BTreeSet<usize> = registrations.iter().flat_map(|(_, events)| events.iter().copied()).collect();
+    let categories: BTreeSet<&str> = events
+        .iter()
+        .filter_map(|event| canonical_event_category(convention.events()[*event].name()))
+        .collect();
+
+    ClubStatistics {
+        name,
+        riders: registrations.len(),
+        females,
+        males,
+        others: registrations.len() - females - males,
+        mean_age: registrations.iter().map(|(registrant, _)| *registrant.age() as f32).sum::<f32>()
+            / registrations.len().max(1) as f32,
+        ages,
+        events: events.len(),
+        categories: categories.len(),
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Export the statistics of each club as a semicolon separated table,
+/// followed by the riders without club, if any.
+pub fn generate_csv_file(report: &ClubReport, brackets: &AgeBrackets) -> String {
+    let mut content = format!(
+        "Club;Riders;Share;Females;Males;Others;Mean age;{};Events;Categories",
+        brackets.labels().join(";")
+    );
+    let lines = report
+        .clubs
+        .iter()
+        .map(|club| (club.name.as_str(), club))
+        .chain(report.without_club.iter().map(|club| ("No club", club)));
+    for (name, club) in lines {
+        content = format!(
+            "{content}\n{name};{};{:.2}%;{};{};{};{:.1};{};{};{}",
+            club.riders,
+            club.riders as f32 / report.registrants.max(1) as f32 * 100.0,
+            club.females,
+            club.males,
+            club.others,
+            club.mean_age,
+            club.ages.iter().map(usize::to_string).collect::<Vec<_>>().join(";"),
+            club.events,
+            club.categories
+        );
+    }
+
+    content
+}
+
+/// Draw the top clubs by riders, each bar split between females, males and other genders.
+///
+/// Once generated, the graph is saved as `clubs.png` in given folder.
+pub fn draw_and_export_leaderboard(report: &ClubReport, top: usize, folder: &Path) -> Result<()> {
+    let file = folder.join("clubs.png");
+    let drawing_area = BitMapBackend::new(&file, (2048, 1024)).into_drawing_area();
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let clubs: Vec<&ClubStatistics> = report.clubs.iter().take(top).collect();
+    let longest_club_name_length = clubs.iter().map(|club| club.name.chars().count()).max().unwrap_or(10);
+    let max_riders_count = clubs.first().map(|club| club.riders).unwrap_or_default();
+    let x_bound = ((max_riders_count + 5) / 5) * 5;
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(40)
+        .caption(format!("Classement des clubs (top {})", clubs.len()), ("sans-serif", 40))
+        .set_label_area_size(LabelAreaPosition::Left, (longest_club_name_length as u32) * 12)
+        .set_label_area_size(LabelAreaPosition::Bottom, 60)
+        .build_cartesian_2d(0..x_bound, (0..clubs.len()).into_segmented())
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .y_label_formatter(&|rank| match rank {
+            // The first club is drawn at the top
+            SegmentValue::CenterOf(rank) if *rank < clubs.len() => clubs[clubs.len() - 1 - rank].name.clone(),
+            _ => String::new(),
+        })
+        .label_style(("sans-serif", 20))
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    // Females, then males, then other genders, each bar starting where the previous one ends
+    for (segment, gender) in [Gender::Female, Gender::Male, Gender::Unspecified].iter().enumerate() {
+        let color = gender_color(gender);
+        chart
+            .draw_series(clubs.iter().rev().enumerate().map(|(rank, club)| {
+                let counts = [club.females, club.males, club.others];
+                let (start, count) = (counts[..segment].iter().sum::<usize>(), counts[segment]);
+                let mut bar = Rectangle::new(
+                    [
+                        (start, SegmentValue::Exact(rank)),
+                        (start + count, SegmentValue::Exact(rank + 1)),
+                    ],
+                    color.filled(),
+                );
+                bar.set_margin(4, 4, 0, 0);
+                bar
+            }))
+            .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+    }
+
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod compute_club_report {
+        use crate::registration::convention::Convention;
+        use crate::registration::event::Event;
+        use crate::registration::gender::Gender;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::clubs::compute_club_report;
+        use crate::test_data::{get_test_convention, get_test_normalized_convention, get_test_registrant};
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let report = compute_club_report(&convention, &AgeBrackets::default());
+
+            assert_eq!(50, *report.registrants());
+            assert_eq!(49, report.clubs().len());
+            let first = &report.clubs()[0];
+            assert_eq!("souffler Club", first.name());
+            assert_eq!(2, *first.riders());
+            assert_eq!(2, first.ages().iter().sum::<usize>());
+            assert_eq!(0, *first.categories());
+            assert_eq!(None, *report.without_club());
+        }
+
+        #[test]
+        fn success_categories() {
+            let convention = get_test_normalized_convention();
+
+            let report = compute_club_report(&convention, &AgeBrackets::default());
+
+            assert!(report.clubs().iter().all(|club| club.categories() <= club.events()));
+            assert!(report.clubs().iter().any(|club| *club.categories() > 1));
+        }
+
+        #[test]
+        fn success_club_spelling_and_no_club() {
+            let convention = Convention::build(
+                vec![
+                    (get_test_registrant(1, 21, Gender::Female, Some("Monocycles Club")), vec![0]),
+                    (get_test_registrant(2, 22, Gender::NonBinary, Some(" monocycles  club")), vec![1]),
+                    (get_test_registrant(3, 23, Gender::Male, Some("Roues Libres")), vec![0]),
+                    (get_test_registrant(4, 24, Gender::Male, None), vec![]),
+                ],
+                vec![vec![]; 4],
+                vec![Event::new(0, "Flat - All".to_string()), Event::new(1, "Trial - All".to_string())],
+            );
+
+            let report = compute_club_report(&convention, &AgeBrackets::default());
+
+            let first = &report.clubs()[0];
+            assert_eq!("Monocycles Club", first.name());
+            assert_eq!((2, 1, 0, 1), (*first.riders(), *first.females(), *first.males(), *first.others()));
+            assert_eq!(21.5, *first.mean_age());
+            assert_eq!(2, *first.events());
+            assert_eq!("Roues Libres", report.clubs()[1].name());
+            assert_eq!(1, *report.without_club().as_ref().unwrap().riders());
+        }
+    }
+
+    mod generate_csv_file {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::clubs::{generate_csv_file, ClubReport, ClubStatistics};
+
+        #[test]
+        fn success() {
+            let club = |name: &str, riders: usize| ClubStatistics {
+                name: name.to_string(),
+                riders,
+                females: riders,
+                males: 0,
+                others: 0,
+                mean_age: 25.0,
+                ages: vec![0, riders],
+                events: 3,
+                categories: 1,
+            };
+            let report = ClubReport {
+                registrants: 4,
+                clubs: vec![club("Monocycles Club", 3)],
+                without_club: Some(club("", 1)),
+            };
+
+            assert_eq!(
+                "Club;Riders;Share;Females;Males;Others;Mean age;0-17;18+;Events;Categories\nMonocycles Club;3;75.00%;3;0;0;25.0;0;3;3;1\nNo club;1;25.00%;1;0;0;25.0;0;1;3;1",
+                generate_csv_file(&report, &AgeBrackets::new(vec![18]))
+            );
+        }
+    }
+
+    mod draw_and_export_leaderboard {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::clubs::{compute_club_report, draw_and_export_leaderboard};
+        use crate::test_data::get_test_convention;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+
+        #[test]
+        fn success() {
+            let folder = temp_dir().join("clubs");
+            create_dir_all(&folder).unwrap();
+            let report = compute_club_report(&get_test_convention(), &AgeBrackets::default());
+
+            draw_and_export_leaderboard(&report, 10, &folder).unwrap();
+
+            assert!(folder.join("clubs.png").exists());
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Hierarchical clustering of events, the closest events being merged first.
+
+use derive_getters::Getters;
+
+/// Two clusters merged into a new one.
+///
+/// Clusters are numbered as in [scipy](https://docs.scipy.org/doc/scipy/reference/generated/scipy.cluster.hierarchy.linkage.html):
+/// the `n` leaves first, then each merge in order, the `i`-th merge creating cluster `n + i`.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct Merge {
+    first: usize,
+    second: usize,
+    distance: f32,
+    /// Leaves of the new cluster.
+    size: usize,
+}
+
+/// Successive merges of the leaves, until a single cluster remains.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct Dendrogram {
+    leaves: usize,
+    merges: Vec<Merge>,
+}
+
+/// Cluster the leaves with average linkage: the distance between two clusters
+/// is the mean distance between a leaf of the first one and a leaf of the second one.
+///
+/// The distance matrix has to be square and symmetric. Ties are merged in the order of the leaves.
+pub fn cluster(distances: &[Vec<f32>]) -> Dendrogram {
+    let leaves = distances.len();
+    // Active clusters, with their id and their leaves
+    let mut clusters: Vec<(usize, Vec<usize>)> = (0..leaves).map(|leaf| (leaf, vec![leaf])).collect();
+    let mut merges = vec![];
+
+    while clusters.len() > 1 {
+        let mut closest = (0, 1, f32::INFINITY);
+        for first in 0..clusters.len() {
+            for second in first + 1..clusters.len() {
+                let distance = average_distance(distances, &clusters[first].1, &clusters[second].1);
+                if distance < closest.2 {
+                    closest = (first, second, distance);
+                }
+            }
+        }
+
+        let (first, second, distance) = closest;
+        let (second_id, second_leaves) = clusters.remove(second);
+        let (first_id, first_leaves) = &mut clusters[first];
+        merges.push(Merge {
+            first: *first_id,
+            second: second_id,
+            distance,
+            size: first_leaves.len() + second_leaves.len(),
+        });
+        *first_id = leaves + merges.len() - 1;
+        first_leaves.extend(second_leaves);
+    }
+
+    Dendrogram { leaves, merges }
+}
+
+fn average_distance(distances: &[Vec<f32>], first: &[usize], second: &[usize]) -> f32 {
+    let total: f32 = first
+        .iter()
+        .flat_map(|first| second.iter().map(move |second| distances[*first][*second]))
+        .sum();
+    total / (first.len() * second.len()) as f32
+}
+
+impl Dendrogram {
+    /// Leaves in the order they are drawn, each cluster's leaves being next to each other.
+    pub fn leaves_order(&self) -> Vec<usize> {
+        match self.merges.last() {
+            None => (0..self.leaves).collect(),
+            Some(_) => {
+                let mut order = vec![];
+                self.collect_leaves(self.leaves + self.merges.len() - 1, &mut order);
+                order
+            }
+        }
+    }
+
+    /// Cut the dendrogram into the given number of clusters, undoing the last merges.
+    /// Each leaf gets the number of its cluster, clusters being numbered from 0 in the order they are drawn.
+    pub fn cut(&self, clusters: usize) -> Vec<usize> {
+        let clusters = clusters.clamp(1, self.leaves.max(1));
+        // Leaves of each cluster, the first merges only
+        let mut members: Vec<Vec<usize>> = (0..self.leaves).map(|leaf| vec![leaf]).collect();
+        for merge in self.merges.iter().take(self.leaves.saturating_sub(clusters)) {
+            let mut leaves = std::mem::take(&mut members[merge.first]);
+            leaves.append(&mut std::mem::take(&mut members[merge.second]));
+            members.push(leaves);
+        }
+
+        let mut labels = vec![0; self.leaves];
+        for (cluster, leaves) in members.iter().filter(|leaves| !leaves.is_empty()).enumerate() {
+            for leaf in leaves {
+                labels[*leaf] = cluster;
+            }
+        }
+
+        let mut drawn: Vec<usize> = vec![];
+        for leaf in self.leaves_order() {
+            if !drawn.contains(&labels[leaf]) {
+                drawn.push(labels[leaf]);
+            }
+        }
+        labels
+            .iter()
+            .map(|label| drawn.iter().position(|cluster| cluster == label).unwrap_or_default())
+            .collect()
+    }
+
+    fn collect_leaves(&self, cluster: usize, order: &mut Vec<usize>) {
+        if cluster < self.leaves {
+            order.push(cluster);
+        } else {
+            let merge = &self.merges[cluster - self.leaves];
+            self.collect_leaves(merge.first, order);
+            self.collect_leaves(merge.second, order);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod cluster {
+        use crate::statistics::clustering::{cluster, Merge};
+
+        #[test]
+        fn success() {
+            let distances = vec![
+                vec![0.0, 1.0, 0.25, 0.75],
+                vec![1.0, 0.0, 0.75, 0.5],
+                vec![0.25, 0.75, 0.0, 1.0],
+                vec![0.75, 0.5, 1.0, 0.0],
+            ];
+
+            let dendrogram = cluster(&distances);
+
+            assert_eq!(
+                &vec![
+                    Merge { first: 0, second: 2, distance: 0.25, size: 2 },
+                    Merge { first: 1, second: 3, distance: 0.5, size: 2 },
+                    Merge { first: 4, second: 5, distance: 0.875, size: 4 },
+                ],
+                dendrogram.merges()
+            );
+            assert_eq!(vec![0, 2, 1, 3], dendrogram.leaves_order());
+        }
+
+        #[test]
+        fn success_cut() {
+            let distances = vec![
+                vec![0.0, 1.0, 0.25, 0.75],
+                vec![1.0, 0.0, 0.75, 0.5],
+                vec![0.25, 0.75, 0.0, 1.0],
+                vec![0.75, 0.5, 1.0, 0.0],
+            ];
+
+            let dendrogram = cluster(&distances);
+
+            assert_eq!(vec![0, 0, 0, 0], dendrogram.cut(1));
+            assert_eq!(vec![0, 1, 0, 1], dendrogram.cut(2));
+            assert_eq!(vec![0, 2, 1, 3], dendrogram.cut(4));
+            assert_eq!(vec![0, 2, 1, 3], dendrogram.cut(10));
+        }
+
+        #[test]
+        fn success_single_leaf() {
+            let dendrogram = cluster(&[vec![0.0]]);
+
+            assert!(dendrogram.merges().is_empty());
+            assert_eq!(vec![0], dendrogram.leaves_order());
+        }
+    }
+}
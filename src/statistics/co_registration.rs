@@ -0,0 +1,305 @@
+//! How strongly events are coupled by the registrants they share.
+//!
+//! Raw overlaps favour big events: every metric here tells a different story.
+//! - `P(second | first)`: share of the first event's participants also entering the second one,
+//! - Jaccard index: shared participants among the participants of either event,
+//! - lift: how much more often both events are entered together than if registrants picked them independently.
+
+use crate::registration::convention::Convention;
+use derive_getters::Getters;
+use std::collections::HashSet;
+
+/// Metric events are compared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CouplingMetric {
+    /// Registrants entering both events.
+    Shared,
+    /// Share of the row event's participants entering the column event.
+    Conditional,
+    #[default]
+    Jaccard,
+    Lift,
+}
+
+/// Participants shared by every couple of events of a convention.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct CoRegistration {
+    /// Names of the events, in the order of [crate::registration::convention::Convention::events].
+    events: Vec<String>,
+    registrants: usize,
+    participants: Vec<usize>,
+    /// Registrants entering both events, indexed like [CoRegistration::events].
+    shared: Vec<Vec<usize>>,
+}
+
+/// Metrics of two distinct events, the first one coming before the second one.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct EventPair {
+    first: usize,
+    second: usize,
+    shared: usize,
+    second_given_first: Option<f32>,
+    first_given_second: Option<f32>,
+    jaccard: Option<f32>,
+    lift: Option<f32>,
+}
+
+/// Count the participants each couple of events shares.
+pub fn compute_co_registration(convention: &Convention) -> CoRegistration {
+    let participants: Vec<HashSet<_>> = convention
+        .participants_by_event()
+        .iter()
+        .map(|participants| participants.iter().collect())
+        .collect();
+
+    CoRegistration {
+        events: convention.events().iter().map(|event| event.name().clone()).collect(),
+        registrants: convention.registrations().len(),
+        participants: participants.iter().map(HashSet::len).collect(),
+        shared: participants
+            .iter()
+            .map(|first| participants.iter().map(|second| first.intersection(second).count()).collect())
+            .collect(),
+    }
+}
+
+impl CoRegistration {
+    /// Value of the metric between two events. Nothing can be said about an event without participants.
+    pub fn metric(&self, metric: CouplingMetric, first: usize, second: usize) -> Option<f32> {
+        let shared = self.shared[first][second] as f32;
+        let (first_participants, second_participants) = (self.participants[first], self.participants[second]);
+        match metric {
+            CouplingMetric::Shared => Some(shared),
+            CouplingMetric::Conditional => ratio(shared, first_participants as f32),
+            CouplingMetric::Jaccard => ratio(shared, (first_participants + second_participants) as f32 - shared),
+            CouplingMetric::Lift => ratio(
+                shared * self.registrants as f32,
+                (first_participants * second_participants) as f32,
+            ),
+        }
+    }
+
+    /// Every couple of distinct events sharing at least one participant.
+    pub fn pairs(&self) -> Vec<EventPair> {
+        let mut pairs = vec![];
+        for first in 0..self.events.len() {
+            for second in first + 1..self.events.len() {
+                if self.shared[first][second] == 0 {
+                    continue;
+                }
+                pairs.push(EventPair {
+                    first,
+                    second,
+                    shared: self.shared[first][second],
+                    second_given_first: self.metric(CouplingMetric::Conditional, first, second),
+                    first_given_second: self.metric(CouplingMetric::Conditional, second, first),
+                    jaccard: self.metric(CouplingMetric::Jaccard, first, second),
+                    lift: self.metric(CouplingMetric::Lift, first, second),
+                });
+            }
+        }
+
+        pairs
+    }
+
+    /// The `count` most coupled couples of events according to the metric,
+    /// the strongest conditional probability counting for [CouplingMetric::Conditional].
+    /// Ties are broken by shared participants.
+    pub fn top_pairs(&self, metric: CouplingMetric, count: usize) -> Vec<EventPair> {
+        let value = |pair: &EventPair| match metric {
+            CouplingMetric::Shared => pair.shared as f32,
+            CouplingMetric::Conditional => pair
+                .second_given_first
+                .unwrap_or_default()
+                .max(pair.first_given_second.unwrap_or_default()),
+            CouplingMetric::Jaccard => pair.jaccard.unwrap_or_default(),
+            CouplingMetric::Lift => pair.lift.unwrap_or_default(),
+        };
+
+        let mut pairs = self.pairs();
+        pairs.sort_by(|first, second| {
+            value(second)
+                .total_cmp(&value(first))
+                .then_with(|| second.shared.cmp(&first.shared))
+        });
+        pairs.truncate(count);
+        pairs
+    }
+}
+
+fn ratio(numerator: f32, denominator: f32) -> Option<f32> {
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+/// Export the metric between every couple of events as a semicolon separated table,
+/// the value in row `i` and column `j` being the metric from event `i` to event `j`.
+pub fn generate_matrix_csv_file(co_registration: &CoRegistration, metric: CouplingMetric) -> String {
+    let events = &co_registration.events;
+    let mut content = format!(";{}", events.join(";"));
+    for (first, name) in events.iter().enumerate() {
+        let cells = (0..events.len())
+            .map(|second| format_metric(co_registration.metric(metric, first, second), metric))
+            .collect::<Vec<_>>()
+            .join(";");
+        content = format!("{content}\n{name};{cells}");
+    }
+
+    content
+}
+
+/// Export every metric of the given couples of events as a semicolon separated table, one line per couple.
+pub fn generate_pairs_csv_file(co_registration: &CoRegistration, pairs: &[EventPair]) -> String {
+    let mut content =
+        "First event;Second event;First participants;Second participants;Shared;P(second|first);P(first|second);Jaccard;Lift"
+            .to_string();
+    for pair in pairs {
+        content = format!(
+            "{content}\n{};{};{};{};{};{};{};{};{}",
+            co_registration.events[pair.first],
+            co_registration.events[pair.second],
+            co_registration.participants[pair.first],
+            co_registration.participants[pair.second],
+            pair.shared,
+            format_metric(pair.second_given_first, CouplingMetric::Conditional),
+            format_metric(pair.first_given_second, CouplingMetric::Conditional),
+            format_metric(pair.jaccard, CouplingMetric::Jaccard),
+            format_metric(pair.lift, CouplingMetric::Lift)
+        );
+    }
+
+    content
+}
+
+fn format_metric(value: Option<f32>, metric: CouplingMetric) -> String {
+    match (value, metric) {
+        (None, _) => String::new(),
+        (Some(value), CouplingMetric::Shared) => format!("{value}"),
+        (Some(value), CouplingMetric::Conditional) => format!("{:.2}%", value * 100.0),
+        (Some(value), _) => format!("{value:.2}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registration::convention::Convention;
+    use crate::registration::event::Event;
+    use crate::registration::gender::Gender;
+    use crate::test_data::get_test_registrant;
+
+    /// Four registrants: Flat and Trial are often entered together, Street only by one of them.
+    fn test_convention() -> Convention {
+        let registrant = |id: u16| get_test_registrant(id, 25, Gender::Female, None);
+        Convention::build(
+            vec![
+                (registrant(1), vec![0, 1]),
+                (registrant(2), vec![0, 1]),
+                (registrant(3), vec![0]),
+                (registrant(4), vec![2]),
+            ],
+            vec![vec![]; 4],
+            vec![
+                Event::new(0, "Flat - All".to_string()),
+                Event::new(1, "Trial - All".to_string()),
+                Event::new(2, "Street - All".to_string()),
+            ],
+        )
+    }
+
+    mod metric {
+        use super::test_convention;
+        use crate::registration::convention::Convention;
+        use crate::registration::event::Event;
+        use crate::statistics::co_registration::{compute_co_registration, CouplingMetric};
+
+        #[test]
+        fn success() {
+            let co_registration = compute_co_registration(&test_convention());
+
+            assert_eq!(Some(2.0), co_registration.metric(CouplingMetric::Shared, 0, 1));
+            assert_eq!(Some(2.0 / 3.0), co_registration.metric(CouplingMetric::Conditional, 0, 1));
+            assert_eq!(Some(1.0), co_registration.metric(CouplingMetric::Conditional, 1, 0));
+            assert_eq!(Some(2.0 / 3.0), co_registration.metric(CouplingMetric::Jaccard, 0, 1));
+            assert_eq!(Some(4.0 / 3.0), co_registration.metric(CouplingMetric::Lift, 0, 1));
+            assert_eq!(Some(0.0), co_registration.metric(CouplingMetric::Lift, 0, 2));
+        }
+
+        #[test]
+        fn success_event_without_participants() {
+            let convention = test_convention();
+            let mut events = convention.events().clone();
+            events.push(Event::new(3, "Hockey - All".to_string()));
+            let convention = Convention::build(convention.registrations().clone(), convention.team_entries().clone(), events);
+
+            let co_registration = compute_co_registration(&convention);
+
+            assert_eq!(None, co_registration.metric(CouplingMetric::Conditional, 3, 0));
+            assert_eq!(None, co_registration.metric(CouplingMetric::Lift, 0, 3));
+            assert_eq!(Some(0.0), co_registration.metric(CouplingMetric::Jaccard, 0, 3));
+        }
+    }
+
+    mod top_pairs {
+        use super::test_convention;
+        use crate::statistics::co_registration::{compute_co_registration, CouplingMetric};
+        use crate::test_data::get_test_convention;
+
+        #[test]
+        fn success() {
+            let co_registration = compute_co_registration(&test_convention());
+
+            let pairs = co_registration.top_pairs(CouplingMetric::Jaccard, 5);
+
+            assert_eq!(1, pairs.len());
+            assert_eq!((0, 1, 2), (*pairs[0].first(), *pairs[0].second(), *pairs[0].shared()));
+            assert_eq!(Some(1.0), *pairs[0].first_given_second());
+        }
+
+        #[test]
+        fn success_sorted() {
+            let co_registration = compute_co_registration(&get_test_convention());
+
+            for metric in [CouplingMetric::Shared, CouplingMetric::Conditional, CouplingMetric::Jaccard, CouplingMetric::Lift] {
+                let pairs = co_registration.top_pairs(metric, 10);
+
+                assert_eq!(10, pairs.len());
+                let values: Vec<f32> = pairs
+                    .iter()
+                    .map(|pair| co_registration.metric(metric, *pair.first(), *pair.second()).unwrap())
+                    .collect();
+                if metric != CouplingMetric::Conditional {
+                    assert!(values.windows(2).all(|values| values[0] >= values[1]));
+                }
+            }
+        }
+    }
+
+    mod generate_matrix_csv_file {
+        use super::test_convention;
+        use crate::statistics::co_registration::{compute_co_registration, generate_matrix_csv_file, CouplingMetric};
+
+        #[test]
+        fn success() {
+            let co_registration = compute_co_registration(&test_convention());
+
+            assert_eq!(
+                ";Flat - All;Trial - All;Street - All\nFlat - All;100.00%;66.67%;0.00%\nTrial - All;100.00%;100.00%;0.00%\nStreet - All;0.00%;0.00%;100.00%",
+                generate_matrix_csv_file(&co_registration, CouplingMetric::Conditional)
+            );
+        }
+    }
+
+    mod generate_pairs_csv_file {
+        use super::test_convention;
+        use crate::statistics::co_registration::{compute_co_registration, generate_pairs_csv_file};
+
+        #[test]
+        fn success() {
+            let co_registration = compute_co_registration(&test_convention());
+
+            assert_eq!(
+                "First event;Second event;First participants;Second participants;Shared;P(second|first);P(first|second);Jaccard;Lift\nFlat - All;Trial - All;3;2;2;66.67%;100.00%;0.67;1.33",
+                generate_pairs_csv_file(&co_registration, &co_registration.pairs())
+            );
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Heatmap of a co-registration metric between every couple of events.
+
+use super::error::Result;
+use crate::configuration::events_mapping::canonical_event_category;
+use crate::statistics::clustering::cluster;
+use crate::statistics::co_registration::{CoRegistration, CouplingMetric};
+use crate::statistics::error::DrawingError;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::path::Path;
+
+/// Order of the events along both axes of the heatmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeatmapOrder {
+    /// Order of the convention's events.
+    #[default]
+    Events,
+    /// Events of a category next to each other, the most popular first.
+    Category,
+    /// Events often entered together next to each other, see [crate::statistics::clustering::cluster].
+    Clustering,
+}
+
+/// Indexes of the events, in the order they are drawn.
+pub fn order_events(co_registration: &CoRegistration, order: HeatmapOrder) -> Vec<usize> {
+    let mut events: Vec<usize> = (0..co_registration.events().len()).collect();
+    match order {
+        HeatmapOrder::Events => {}
+        HeatmapOrder::Category => events.sort_by(|first, second| {
+            let category = |event: &usize| canonical_event_category(&co_registration.events()[*event]);
+            category(first)
+                .cmp(&category(second))
+                .then_with(|| co_registration.participants()[*second].cmp(&co_registration.participants()[*first]))
+        }),
+        HeatmapOrder::Clustering => events = cluster(&jaccard_distances(co_registration)).leaves_order(),
+    }
+
+    events
+}
+
+/// Distance between two events: the share of their participants who did not enter both.
+pub fn jaccard_distances(co_registration: &CoRegistration) -> Vec<Vec<f32>> {
+    let events = co_registration.events().len();
+    (0..events)
+        .map(|first| {
+            (0..events)
+                .map(|second| match first == second {
+                    true => 0.0,
+                    false => 1.0 - co_registration.metric(CouplingMetric::Jaccard, first, second).unwrap_or_default(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Draw the metric between every couple of events as a grid of cells, from white to red.
+/// Row events come first when the metric is not symmetric, e.g. the share of the row event's participants
+/// entering the column event.
+///
+/// The graph is saved to given file, as an SVG image if its extension is `svg`, as a bitmap otherwise.
+pub fn draw_and_export_heatmap(
+    co_registration: &CoRegistration,
+    metric: CouplingMetric,
+    order: &[usize],
+    file: &Path,
+) -> Result<()> {
+    let label_area_size = co_registration
+        .events()
+        .iter()
+        .map(|event| event.chars().count())
+        .max()
+        .unwrap_or(10) as u32
+        * 10;
+    let size = 2048.max(order.len() as u32 * 30 + label_area_size);
+
+    match file.extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => draw_heatmap(SVGBackend::new(file, (size, size)).into_drawing_area(), co_registration, metric, order, label_area_size),
+        _ => draw_heatmap(BitMapBackend::new(file, (size, size)).into_drawing_area(), co_registration, metric, order, label_area_size),
+    }
+}
+
+fn draw_heatmap<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, Shift>,
+    co_registration: &CoRegistration,
+    metric: CouplingMetric,
+    order: &[usize],
+    label_area_size: u32,
+) -> Result<()> {
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let events = order.len();
+    let value = |row: usize, column: usize| co_registration.metric(metric, order[row], order[column]);
+    // The diagonal would outshine every other cell: the scale only considers distinct events
+    let max_value = (0..events)
+        .flat_map(|row| (0..events).filter(move |column| *column != row).map(move |column| (row, column)))
+        .filter_map(|(row, column)| value(row, column))
+        .fold(0.0_f32, f32::max);
+    let name = |index: &usize| {
+        co_registration
+            .events()
+            .get(order.get(*index).copied().unwrap_or(usize::MAX))
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(20)
+        .caption(
+            format!("Co-inscriptions par épreuve ({}, max {max_value:.2})", metric_name(metric)),
+            ("sans-serif", 40),
+        )
+        .set_label_area_size(LabelAreaPosition::Left, label_area_size)
+        .set_label_area_size(LabelAreaPosition::Bottom, label_area_size)
+        .build_cartesian_2d(
+            (0..events.saturating_sub(1)).into_segmented(),
+            (0..events.saturating_sub(1)).into_segmented(),
+        )
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_labels(events)
+        .y_labels(events)
+        // Rotated labels would be centered on their column's tick, and cut: they are drawn below instead
+        .x_label_formatter(&|_| String::new())
+        // The first event is drawn at the top
+        .y_label_formatter(&|row| match row {
+            SegmentValue::CenterOf(row) if *row < events => name(&(events - 1 - row)),
+            _ => String::new(),
+        })
+        .y_label_style(("sans-serif", 16))
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    chart
+        .draw_series((0..events).flat_map(|row| {
+            (0..events).map(move |column| {
+                let color = match (row == column, value(row, column)) {
+                    (true, _) | (_, None) => RGBColor(200, 200, 200),
+                    (false, Some(value)) => heat_color(value / max_value.max(f32::EPSILON)),
+                };
+                let y = events - 1 - row;
+                Rectangle::new(
+                    [
+                        (SegmentValue::Exact(column), SegmentValue::Exact(y)),
+                        (SegmentValue::Exact(column + 1), SegmentValue::Exact(y + 1)),
+                    ],
+                    color.filled(),
+                )
+            })
+        }))
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+
+    let font_desc = FontDesc::new(FontFamily::SansSerif, 16_f64, FontStyle::Normal).transform(FontTransform::Rotate90);
+    chart
+        .draw_series((0..events).map(|column| {
+            Text::new(
+                format!("  {}", name(&column)),
+                (SegmentValue::CenterOf(column), SegmentValue::Exact(0)),
+                font_desc.clone(),
+            )
+        }))
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
+
+/// From white for 0 to red for 1.
+fn heat_color(intensity: f32) -> RGBColor {
+    let fade = (255.0 * (1.0 - intensity.clamp(0.0, 1.0))) as u8;
+    RGBColor(255, fade, fade)
+}
+
+fn metric_name(metric: CouplingMetric) -> &'static str {
+    match metric {
+        CouplingMetric::Shared => "inscrits communs",
+        CouplingMetric::Conditional => "probabilité conditionnelle",
+        CouplingMetric::Jaccard => "indice de Jaccard",
+        CouplingMetric::Lift => "lift",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod order_events {
+        use crate::statistics::co_registration::compute_co_registration;
+        use crate::statistics::co_registration_heatmap::{order_events, HeatmapOrder};
+        use crate::test_data::{get_test_convention, get_test_normalized_convention};
+
+        #[test]
+        fn success_category() {
+            let convention = get_test_normalized_convention();
+            let co_registration = compute_co_registration(&convention);
+
+            let order = order_events(&co_registration, HeatmapOrder::Category);
+
+            let categories: Vec<&str> = order
+                .iter()
+                .map(|event| co_registration.events()[*event].split_once('/').unwrap().0)
+                .collect();
+            assert!(categories.windows(2).all(|categories| categories[0] <= categories[1]));
+            let athletics: Vec<usize> = order
+                .iter()
+                .filter(|event| co_registration.events()[**event].starts_with("athletisme/"))
+                .map(|event| co_registration.participants()[*event])
+                .collect();
+            assert!(athletics.windows(2).all(|participants| participants[0] >= participants[1]));
+        }
+
+        #[test]
+        fn success_clustering() {
+            let co_registration = compute_co_registration(&get_test_convention());
+
+            let mut order = order_events(&co_registration, HeatmapOrder::Clustering);
+
+            assert_ne!((0..co_registration.events().len()).collect::<Vec<_>>(), order);
+            order.sort();
+            assert_eq!((0..co_registration.events().len()).collect::<Vec<_>>(), order);
+        }
+    }
+
+    mod draw_and_export_heatmap {
+        use crate::statistics::co_registration::{compute_co_registration, CouplingMetric};
+        use crate::statistics::co_registration_heatmap::{draw_and_export_heatmap, order_events, HeatmapOrder};
+        use crate::test_data::get_test_convention;
+        use parameterized::parameterized;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(file = { "heatmap.png", "heatmap.svg" })]
+        fn success(file: &str) {
+            let folder = temp_dir().join("co-registration-heatmap");
+            create_dir_all(&folder).unwrap();
+            let co_registration = compute_co_registration(&get_test_convention());
+            let order = order_events(&co_registration, HeatmapOrder::Clustering);
+
+            draw_and_export_heatmap(&co_registration, CouplingMetric::Conditional, &order, &folder.join(file)).unwrap();
+
+            assert!(folder.join(file).exists());
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Families of riders: events often entered together, compared with the official categories.
+
+use super::error::Result;
+use crate::configuration::events_configuration::EventsConfiguration;
+use crate::configuration::events_mapping::canonical_event_category;
+use crate::registration::convention::Convention;
+use crate::statistics::clustering::{cluster, Dendrogram};
+use crate::statistics::co_registration::compute_co_registration;
+use crate::statistics::co_registration_heatmap::jaccard_distances;
+use crate::statistics::error::DrawingError;
+use derive_getters::Getters;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::path::Path;
+
+/// Events clustered by their shared participants, see [crate::statistics::clustering::cluster].
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct EventFamilies {
+    /// Events with at least one participant, the others having nothing to share.
+    events: Vec<String>,
+    /// Display name of each event's official category, known for canonical events only.
+    categories: Vec<Option<String>>,
+    participants: Vec<usize>,
+    /// Leaves are indexed like [EventFamilies::events].
+    dendrogram: Dendrogram,
+    /// Family of each event, families being numbered from 0 in the order they are drawn.
+    families: Vec<usize>,
+}
+
+/// Cluster the events with the Jaccard distance, i.e. the share of their participants who did not enter both,
+/// then cut the dendrogram into the given number of families.
+///
+/// Official categories are read from the configuration, the convention being normalized first.
+pub fn compute_event_families(
+    convention: &Convention,
+    configuration: &EventsConfiguration,
+    families: usize,
+) -> EventFamilies {
+    let co_registration = compute_co_registration(convention);
+    let kept: Vec<usize> = (0..co_registration.events().len())
+        .filter(|event| co_registration.participants()[*event] > 0)
+        .collect();
+    let distances = jaccard_distances(&co_registration);
+    let distances: Vec<Vec<f32>> = kept
+        .iter()
+        .map(|first| kept.iter().map(|second| distances[*first][*second]).collect())
+        .collect();
+    let dendrogram = cluster(&distances);
+
+    let events: Vec<String> = kept.iter().map(|event| co_registration.events()[*event].clone()).collect();
+    let categories = events
+        .iter()
+        .map(|event| {
+            canonical_event_category(event)
+                .and_then(|category| configuration.categories().get(category))
+                .map(|category| category.name().clone())
+        })
+        .collect();
+
+    EventFamilies {
+        events,
+        categories,
+        participants: kept.iter().map(|event| co_registration.participants()[*event]).collect(),
+        families: dendrogram.cut(families),
+        dendrogram,
+    }
+}
+
+impl EventFamilies {
+    pub fn families_count(&self) -> usize {
+        self.families.iter().max().map_or(0, |family| family + 1)
+    }
+
+    /// Share of couples of events on which families and categories agree: both in the same family and category,
+    /// or in different ones. Only events with a category count, nothing being said when less than two have one.
+    pub fn rand_index(&self) -> Option<f32> {
+        let events: Vec<(usize, &String)> = self
+            .families
+            .iter()
+            .zip(&self.categories)
+            .filter_map(|(family, category)| category.as_ref().map(|category| (*family, category)))
+            .collect();
+        let mut couples = 0;
+        let mut agreements = 0;
+        for (index, (first_family, first_category)) in events.iter().enumerate() {
+            for (second_family, second_category) in &events[index + 1..] {
+                couples += 1;
+                if (first_family == second_family) == (first_category == second_category) {
+                    agreements += 1;
+                }
+            }
+        }
+
+        (couples > 0).then(|| agreements as f32 / couples as f32)
+    }
+
+    /// The category most of the family's events belong to, with the share of the family's events belonging to it.
+    /// Ties go to the category coming first alphabetically.
+    pub fn main_category(&self, family: usize) -> Option<(&String, f32)> {
+        let categories: Vec<&Option<String>> = self
+            .families
+            .iter()
+            .zip(&self.categories)
+            .filter(|(event_family, _)| **event_family == family)
+            .map(|(_, category)| category)
+            .collect();
+        let mut counts: Vec<(&String, usize)> = vec![];
+        for category in categories.iter().copied().flatten() {
+            match counts.iter_mut().find(|(counted, _)| *counted == category) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((category, 1)),
+            }
+        }
+        counts.sort_by(|first, second| second.1.cmp(&first.1).then(first.0.cmp(second.0)));
+
+        counts
+            .first()
+            .map(|(category, count)| (*category, *count as f32 / categories.len() as f32))
+    }
+}
+
+/// Export the events as a semicolon separated table, family by family in the order they are drawn.
+pub fn generate_csv_file(families: &EventFamilies) -> String {
+    let mut content = "Family;Event;Category;Participants;Family main category".to_string();
+    for event in families.dendrogram.leaves_order() {
+        let family = families.families[event];
+        let main_category = families
+            .main_category(family)
+            .map(|(category, share)| format!("{category} ({:.2}%)", share * 100.0))
+            .unwrap_or_default();
+        content = format!(
+            "{content}\n{};{};{};{};{main_category}",
+            family + 1,
+            families.events[event],
+            families.categories[event].as_deref().unwrap_or_default(),
+            families.participants[event]
+        );
+    }
+
+    content
+}
+
+/// Draw the dendrogram, each family's branches in its own colour, the branches merging families in black.
+/// Events are labelled with their official category.
+///
+/// The graph is saved to given file, as an SVG image if its extension is `svg`, as a bitmap otherwise.
+pub fn draw_and_export_dendrogram(families: &EventFamilies, file: &Path) -> Result<()> {
+    let label_area_size = families
+        .events
+        .iter()
+        .zip(&families.categories)
+        .map(|(event, category)| event.chars().count() + category.as_ref().map_or(0, |category| category.chars().count() + 3))
+        .max()
+        .unwrap_or(10) as u32
+        * 10;
+    let size = (2048.max(families.events.len() as u32 * 30), 1024 + label_area_size);
+
+    match file.extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => draw_dendrogram(SVGBackend::new(file, size).into_drawing_area(), families, label_area_size),
+        _ => draw_dendrogram(BitMapBackend::new(file, size).into_drawing_area(), families, label_area_size),
+    }
+}
+
+fn draw_dendrogram<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, Shift>,
+    families: &EventFamilies,
+    label_area_size: u32,
+) -> Result<()> {
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let leaves = families.events.len();
+    let merges = families.dendrogram.merges();
+    let max_distance = merges.iter().map(|merge| *merge.distance()).fold(0.0_f32, f32::max).max(f32::EPSILON);
+    let caption = match families.rand_index() {
+        Some(rand_index) => format!("Familles d'épreuves ({} familles, indice de Rand {rand_index:.2})", families.families_count()),
+        None => format!("Familles d'épreuves ({} familles)", families.families_count()),
+    };
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(20)
+        .caption(caption, ("sans-serif", 40))
+        .set_label_area_size(LabelAreaPosition::Left, 60)
+        .set_label_area_size(LabelAreaPosition::Bottom, label_area_size)
+        .build_cartesian_2d(-0.5_f32..leaves as f32 - 0.5, 0.0_f32..max_distance * 1.05)
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_x_axis()
+        .y_desc("Distance de Jaccard")
+        .y_label_style(("sans-serif", 16))
+        .axis_desc_style(("sans-serif", 20))
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    // Position, height and family of each cluster, numbered as in the dendrogram
+    let mut clusters: Vec<(f32, f32, Option<usize>)> = vec![(0.0, 0.0, None); leaves];
+    for (position, leaf) in families.dendrogram.leaves_order().iter().enumerate() {
+        clusters[*leaf] = (position as f32, 0.0, Some(families.families[*leaf]));
+    }
+    for merge in merges {
+        let (first, second) = (clusters[*merge.first()], clusters[*merge.second()]);
+        let family = first.2.filter(|family| second.2 == Some(*family));
+        let color = match family {
+            Some(family) => Palette99::pick(family).to_rgba(),
+            None => BLACK.to_rgba(),
+        };
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![
+                    (first.0, first.1),
+                    (first.0, *merge.distance()),
+                    (second.0, *merge.distance()),
+                    (second.0, second.1),
+                ],
+                color.stroke_width(3),
+            )))
+            .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+        clusters.push(((first.0 + second.0) / 2.0, *merge.distance(), family));
+    }
+
+    let font_desc = FontDesc::new(FontFamily::SansSerif, 16_f64, FontStyle::Normal).transform(FontTransform::Rotate90);
+    chart
+        .draw_series(families.dendrogram.leaves_order().iter().enumerate().map(|(position, leaf)| {
+            let label = match &families.categories[*leaf] {
+                Some(category) => format!("  {} ({category})", families.events[*leaf]),
+                None => format!("  {}", families.events[*leaf]),
+            };
+            Text::new(label, (position as f32, 0.0), font_desc.clone())
+        }))
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configuration::events_configuration::{EventsCategory, EventsConfiguration};
+    use crate::registration::convention::Convention;
+    use crate::registration::event::Event;
+    use crate::registration::gender::Gender;
+    use crate::test_data::get_test_registrant;
+    use std::collections::HashMap;
+
+    /// Urban riders entering Street and Flat, road riders entering both road races, one of them also entering Flat.
+    fn test_convention() -> (Convention, EventsConfiguration) {
+        let registrant = |id: u16| get_test_registrant(id, 25, Gender::Female, None);
+        let convention = Convention::build(
+            vec![
+                (registrant(1), vec![0, 1]),
+                (registrant(2), vec![0, 1]),
+                (registrant(3), vec![2, 3]),
+                (registrant(4), vec![1, 2, 3]),
+            ],
+            vec![vec![]; 4],
+            vec![
+                Event::new(0, "urbain/street".to_string()),
+                Event::new(1, "urbain/flat".to_string()),
+                Event::new(2, "courses-sur-route/10km".to_string()),
+                Event::new(3, "courses-sur-route/marathon".to_string()),
+                Event::new(4, "artistique/individuel".to_string()),
+            ],
+        );
+        let category = |name: &str| EventsCategory::new(name.to_string(), HashMap::new());
+        let configuration = EventsConfiguration::new(HashMap::from([
+            ("urbain".to_string(), category("Urbain")),
+            ("courses-sur-route".to_string(), category("Courses sur route")),
+            ("artistique".to_string(), category("Artistique")),
+        ]));
+
+        (convention, configuration)
+    }
+
+    mod compute_event_families {
+        use super::test_convention;
+        use crate::configuration::events_configuration::load_configuration;
+        use crate::statistics::event_families::compute_event_families;
+        use crate::test_data::get_test_normalized_convention;
+        use std::path::PathBuf;
+
+        #[test]
+        fn success() {
+            let (convention, configuration) = test_convention();
+
+            let families = compute_event_families(&convention, &configuration, 2);
+
+            assert_eq!(4, families.events().len());
+            assert_eq!(vec![0, 0, 1, 1], *families.families());
+            assert_eq!(Some("Urbain".to_string()), families.categories()[0]);
+            assert_eq!(Some(1.0), families.rand_index());
+            assert_eq!(Some((&"Courses sur route".to_string(), 1.0)), families.main_category(1));
+        }
+
+        #[test]
+        fn success_mapped_convention() {
+            let configuration = load_configuration(&PathBuf::from(format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR")))).unwrap();
+            let convention = get_test_normalized_convention();
+
+            let families = compute_event_families(&convention, &configuration, 6);
+
+            assert_eq!(6, families.families_count());
+            assert!(families.categories().iter().all(Option::is_some));
+            let rand_index = families.rand_index().unwrap();
+            assert!((0.0..=1.0).contains(&rand_index));
+        }
+    }
+
+    mod generate_csv_file {
+        use super::test_convention;
+        use crate::statistics::event_families::{compute_event_families, generate_csv_file};
+
+        #[test]
+        fn success() {
+            let (convention, configuration) = test_convention();
+            let families = compute_event_families(&convention, &configuration, 2);
+
+            assert_eq!(
+                "Family;Event;Category;Participants;Family main category\n1;urbain/street;Urbain;2;Urbain (100.00%)\n1;urbain/flat;Urbain;3;Urbain (100.00%)\n2;courses-sur-route/10km;Courses sur route;2;Courses sur route (100.00%)\n2;courses-sur-route/marathon;Courses sur route;2;Courses sur route (100.00%)",
+                generate_csv_file(&families)
+            );
+        }
+    }
+
+    mod draw_and_export_dendrogram {
+        use crate::configuration::events_configuration::load_configuration;
+        use crate::statistics::event_families::{compute_event_families, draw_and_export_dendrogram};
+        use crate::test_data::get_test_normalized_convention;
+        use parameterized::parameterized;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+        use std::path::PathBuf;
+
+        #[test]
+        #[ignore]
+        fn ide_support() {
+            // This ignored test is mandatory for IntelliJ to detect tests in this module.
+        }
+
+        #[parameterized(file = { "dendrogram.png", "dendrogram.svg" })]
+        fn success(file: &str) {
+            let folder = temp_dir().join("event-families");
+            create_dir_all(&folder).unwrap();
+            let configuration = load_configuration(&PathBuf::from(format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR")))).unwrap();
+            let families = compute_event_families(&get_test_normalized_convention(), &configuration, 6);
+
+            draw_and_export_dendrogram(&families, &folder.join(file)).unwrap();
+
+            assert!(folder.join(file).exists());
+        }
+    }
+}
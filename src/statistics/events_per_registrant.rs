@@ -0,0 +1,264 @@
+//! How many events each registrant entered.
+
+use super::error::Result;
+use crate::registration::convention::Convention;
+use crate::registration::gender::Gender;
+use crate::registration::registrant::Registrant;
+use crate::statistics::age_brackets::AgeBrackets;
+use crate::statistics::error::DrawingError;
+use derive_getters::Getters;
+use plotters::prelude::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Mean and median number of events of a group of registrants.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct EventsCountSummary {
+    registrants: usize,
+    mean: f32,
+    median: f32,
+}
+
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct EventsPerRegistrant {
+    /// Registrants having entered as many events as the index, from 0 to the busiest registrant's count.
+    histogram: Vec<usize>,
+    overall: EventsCountSummary,
+    /// Sorted by gender. Genders nobody registered with are left out.
+    by_gender: Vec<(Gender, EventsCountSummary)>,
+    /// Sorted by age bracket, see [AgeBrackets::label]. Empty brackets are left out.
+    by_bracket: Vec<(usize, EventsCountSummary)>,
+    /// Registrants who entered no event at all.
+    idle: Vec<Registrant>,
+}
+
+/// Count the events of every registrant of the convention.
+pub fn compute_events_per_registrant(convention: &Convention, brackets: &AgeBrackets) -> EventsPerRegistrant {
+    let registrations = convention.registrations();
+
+    let mut histogram = vec![0; registrations.iter().map(|(_, events)| events.len()).max().unwrap_or_default() + 1];
+    let mut by_gender: BTreeMap<Gender, Vec<usize>> = BTreeMap::new();
+    let mut by_bracket: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (registrant, events) in registrations {
+        histogram[events.len()] += 1;
+        by_gender.entry(registrant.gender().clone()).or_default().push(events.len());
+        by_bracket.entry(brackets.bracket(*registrant.age())).or_default().push(events.len());
+    }
+
+    EventsPerRegistrant {
+        histogram,
+        overall: summarize(registrations.iter().map(|(_, events)| events.len()).collect()),
+        by_gender: by_gender.into_iter().map(|(gender, counts)| (gender, summarize(counts))).collect(),
+        by_bracket: by_bracket.into_iter().map(|(bracket, counts)| (bracket, summarize(counts))).collect(),
+        idle: registrations
+            .iter()
+            .filter(|(_, events)| events.is_empty())
+            .map(|(registrant, _)| registrant.clone())
+            .collect(),
+    }
+}
+
+fn summarize(mut counts: Vec<usize>) -> EventsCountSummary {
+    counts.sort();
+    let registrants = counts.len();
+    let (mean, median) = match registrants {
+        0 => (0.0, 0.0),
+        _ => {
+            let mean = counts.iter().sum::<usize>() as f32 / registrants as f32;
+            let median = if registrants.is_multiple_of(2) {
+                (counts[registrants / 2 - 1] + counts[registrants / 2]) as f32 / 2.0
+            } else {
+                counts[registrants / 2] as f32
+            };
+            (mean, median)
+        }
+    };
+
+    EventsCountSummary { registrants, mean, median }
+}
+
+/// Export how many registrants entered each number of events, as a semicolon separated table.
+pub fn generate_histogram_csv_file(statistics: &EventsPerRegistrant) -> String {
+    let mut content = "Events;Registrants".to_string();
+    for (events, registrants) in statistics.histogram.iter().enumerate() {
+        content = format!("{content}\n{events};{registrants}");
+    }
+
+    content
+}
+
+/// Export the mean and median number of events of every registrant, then per gender and per age bracket,
+/// as a semicolon separated table.
+pub fn generate_summary_csv_file(statistics: &EventsPerRegistrant, brackets: &AgeBrackets) -> String {
+    let lines = std::iter::once(("All".to_string(), &statistics.overall))
+        .chain(statistics.by_gender.iter().map(|(gender, summary)| (gender.to_string(), summary)))
+        .chain(statistics.by_bracket.iter().map(|(bracket, summary)| (brackets.label(*bracket), summary)));
+
+    let mut content = "Group;Registrants;Mean;Median".to_string();
+    for (group, summary) in lines {
+        content = format!("{content}\n{group};{};{:.2};{:.1}", summary.registrants, summary.mean, summary.median);
+    }
+
+    content
+}
+
+/// List the registrants who entered no event, as a semicolon separated table.
+pub fn generate_idle_csv_file(statistics: &EventsPerRegistrant) -> String {
+    let mut content = "Id;First Name;Last Name;Club".to_string();
+    for registrant in &statistics.idle {
+        content = format!(
+            "{content}\n{};{};{};{}",
+            registrant.id(),
+            registrant.first_name(),
+            registrant.last_name(),
+            registrant.club().clone().unwrap_or_default()
+        );
+    }
+
+    content
+}
+
+/// Draw a histogram with a bar per number of events, saved as `events-count.png` in given folder.
+pub fn draw_and_export_histogram(statistics: &EventsPerRegistrant, folder: &Path) -> Result<()> {
+    let file = folder.join("events-count.png");
+    let drawing_area = BitMapBackend::new(&file, (2048, 1024)).into_drawing_area();
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let histogram = &statistics.histogram;
+    let max_registrants_count = histogram.iter().copied().max().unwrap_or(10);
+    let upper_y_bound = ((max_registrants_count + 10) / 10) * 10;
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(20)
+        .set_label_area_size(LabelAreaPosition::Left, 60)
+        .set_label_area_size(LabelAreaPosition::Bottom, 60)
+        .caption("Nombre d'épreuves par inscrit", ("sans-serif", 40))
+        .build_cartesian_2d((0..histogram.len() - 1).into_segmented(), 0..upper_y_bound)
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Épreuves")
+        .y_desc("Inscrits")
+        .label_style(("sans-serif", 20))
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    chart
+        .draw_series(
+            Histogram::vertical(&chart)
+                .style(BLUE.filled())
+                .margin(10)
+                .data(histogram.iter().enumerate().map(|(events, registrants)| (events, *registrants))),
+        )
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod compute_events_per_registrant {
+        use crate::registration::convention::Convention;
+        use crate::registration::event::Event;
+        use crate::registration::gender::Gender;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::events_per_registrant::{compute_events_per_registrant, EventsCountSummary};
+        use crate::test_data::{get_test_convention, get_test_registrant};
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let statistics = compute_events_per_registrant(&convention, &AgeBrackets::default());
+
+            assert_eq!(convention.registrations().len(), statistics.histogram().iter().sum::<usize>());
+            assert_eq!(convention.registrations().len(), *statistics.overall().registrants());
+            assert_eq!(
+                convention.registrations().len(),
+                statistics.by_gender().iter().map(|(_, summary)| summary.registrants()).sum::<usize>()
+            );
+            let participations: usize = convention.participants_by_event().iter().map(Vec::len).sum();
+            assert_eq!(participations as f32 / convention.registrations().len() as f32, *statistics.overall().mean());
+            assert_eq!(statistics.histogram()[0], statistics.idle().len());
+        }
+
+        #[test]
+        fn success_idle_registrant() {
+            let registrant = |id: u16, age: u8, gender: Gender| get_test_registrant(id, age, gender, None);
+            let idle = registrant(3, 40, Gender::Male);
+            let convention = Convention::build(
+                vec![
+                    (registrant(1, 12, Gender::Female), vec![0, 1, 2]),
+                    (registrant(2, 12, Gender::Male), vec![1]),
+                    (idle.clone(), vec![]),
+                ],
+                vec![vec![], vec![], vec![]],
+                vec![Event::new(0, "Flat - All".to_string()), Event::new(1, "Trial - All".to_string()), Event::new(2, "Street - All".to_string())],
+            );
+
+            let statistics = compute_events_per_registrant(&convention, &AgeBrackets::default());
+
+            assert_eq!(&vec![1, 1, 0, 1], statistics.histogram());
+            assert_eq!(&EventsCountSummary { registrants: 3, mean: 4.0 / 3.0, median: 1.0 }, statistics.overall());
+            assert_eq!(
+                &vec![
+                    (1, EventsCountSummary { registrants: 2, mean: 2.0, median: 2.0 }),
+                    (6, EventsCountSummary { registrants: 1, mean: 0.0, median: 0.0 }),
+                ],
+                statistics.by_bracket()
+            );
+            assert_eq!(&vec![idle], statistics.idle());
+        }
+    }
+
+    mod generate_summary_csv_file {
+        use crate::registration::gender::Gender;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::events_per_registrant::{generate_summary_csv_file, EventsCountSummary, EventsPerRegistrant};
+
+        #[test]
+        fn success() {
+            let statistics = EventsPerRegistrant {
+                histogram: vec![0, 1, 1],
+                overall: EventsCountSummary { registrants: 2, mean: 1.5, median: 1.5 },
+                by_gender: vec![
+                    (Gender::Female, EventsCountSummary { registrants: 1, mean: 1.0, median: 1.0 }),
+                    (Gender::Male, EventsCountSummary { registrants: 1, mean: 2.0, median: 2.0 }),
+                ],
+                by_bracket: vec![(1, EventsCountSummary { registrants: 2, mean: 1.5, median: 1.5 })],
+                idle: vec![],
+            };
+
+            assert_eq!(
+                "Group;Registrants;Mean;Median\nAll;2;1.50;1.5\nFemale;1;1.00;1.0\nMale;1;2.00;2.0\n11-12;2;1.50;1.5",
+                generate_summary_csv_file(&statistics, &AgeBrackets::default())
+            );
+        }
+    }
+
+    mod draw_and_export_histogram {
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::events_per_registrant::{compute_events_per_registrant, draw_and_export_histogram};
+        use crate::test_data::get_test_convention;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+
+        #[test]
+        fn success() {
+            let folder = temp_dir().join("events-per-registrant");
+            create_dir_all(&folder).unwrap();
+            let statistics = compute_events_per_registrant(&get_test_convention(), &AgeBrackets::default());
+
+            draw_and_export_histogram(&statistics, &folder).unwrap();
+
+            assert!(folder.join("events-count.png").exists());
+        }
+    }
+}
@@ -1,12 +1,12 @@
 //! Whether a couple of events shares a lot of registrants.
 
-use crate::registration::convention::load_convention;
+use crate::registration::convention::Convention;
 use crate::registration::registrant::Registrant;
 use std::collections::HashSet;
-use std::path::PathBuf;
 
-pub fn generate_csv_file(file: &PathBuf) -> String {
-    let convention = load_convention(file).unwrap();
+/// Compute for each couple of events of the convention
+/// how many registrants they share, as a semicolon separated table.
+pub fn generate_csv_file(convention: &Convention) -> String {
     let registrants = convention.participants_by_event();
     let dependencies = compute_dependencies(registrants);
     let dependencies: Vec<Vec<String>> = dependencies
@@ -24,7 +24,7 @@ pub fn generate_csv_file(file: &PathBuf) -> String {
         .iter()
         .map(|event| event.name().clone())
         .reduce(|acc, name| format!("{acc};{name}"))
-        .unwrap());
+        .unwrap_or_default());
 
     for (i, event) in events.iter().enumerate() {
         let line = dependencies
@@ -33,7 +33,7 @@ pub fn generate_csv_file(file: &PathBuf) -> String {
             .iter()
             .cloned()
             .reduce(|acc, dependency| format!("{acc};{dependency}"))
-            .unwrap();
+            .unwrap_or_default();
         content = format!("{content}\n{};{line}", event.name());
     }
 
@@ -72,13 +72,14 @@ fn compute_dependency(registrants_1: &HashSet<&Registrant>, registrants_2: &Hash
 mod tests {
     use crate::registration::gender::Gender;
     use crate::registration::registrant::Registrant;
+    use crate::test_data::birthday;
 
     fn test_registrants() -> (Registrant, Registrant, Registrant, Registrant) {
         let r1 = Registrant::new(
             1,
             "John".to_string(),
             "Doe".to_string(),
-            "01.01.1970".to_string(),
+            birthday("01.01.1970"),
             55,
             Gender::Male,
             None,
@@ -87,7 +88,7 @@ mod tests {
             2,
             "Dominique".to_string(),
             "Jacques".to_string(),
-            "12.10.1962".to_string(),
+            birthday("12.10.1962"),
             63,
             Gender::Female,
             None,
@@ -96,7 +97,7 @@ mod tests {
             3,
             "Jeanne".to_string(),
             "Marie".to_string(),
-            "22.08.1957".to_string(),
+            birthday("22.08.1957"),
             68,
             Gender::Female,
             None,
@@ -105,7 +106,7 @@ mod tests {
             4,
             "Timéo".to_string(),
             "Bernard".to_string(),
-            "18.03.2012".to_string(),
+            birthday("18.03.2012"),
             13,
             Gender::Male,
             None,
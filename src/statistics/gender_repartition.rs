@@ -2,7 +2,7 @@ use super::error::Result;
 use crate::registration::convention::Convention;
 use crate::registration::event::Event;
 use crate::registration::gender::Gender;
-use crate::registration::gender::Gender::{Female, Male};
+use crate::registration::gender::Gender::{Female, Male, NonBinary, Unspecified};
 use crate::statistics::error::DrawingError;
 use plotters::coord::types::{RangedCoordf32, RangedCoordi32};
 use plotters::coord::Shift;
@@ -10,12 +10,14 @@ use plotters::prelude::{FontTransform::*, *};
 use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
+/// Order of the bars of each event. Genders nobody registered with are left out.
+const GENDERS: [Gender; 4] = [Female, Male, NonBinary, Unspecified];
+
 /// Draw a histogram based on the convention's data.
 /// The histogram represents for each event of the convention
-/// the repartition of participants between males and females.
+/// the repartition of participants between females, males and, if any, other genders.
 ///
 /// Once generated, the graph is saved to a new file in given folder.
-#[allow(dead_code)]
 pub fn draw_and_export_graph(
     convention: &Convention,
     year: u16,
@@ -36,6 +38,7 @@ fn draw_graph_by_gender_by_event<'a>(
     file: &'a PathBuf,
 ) -> Result<DrawingArea<BitMapBackend<'a>, Shift>> {
     let data = group_by_gender_by_event(convention);
+    let genders = compute_displayed_genders(&data);
 
     let root_drawing_area = create_drawing_area(file);
     let root_drawing_area = init_drawing_area(root_drawing_area)?;
@@ -51,10 +54,10 @@ fn draw_graph_by_gender_by_event<'a>(
         &root_drawing_area,
         margin_bottom,
         &caption,
-        events_count as f32 * 2.0,
+        (events_count * genders.len()) as f32,
         upper_y_bound,
     )?;
-    draw_chart(&mut chart, &data, events_count)?;
+    draw_chart(&mut chart, &data, &genders, events_count)?;
 
     Ok(root_drawing_area)
 }
@@ -88,6 +91,28 @@ fn compute_longest_event_name_length(convention: &Convention) -> usize {
         .unwrap_or(10)
 }
 
+/// Females and males always get a bar, other genders only when someone registered with them.
+fn compute_displayed_genders(data: &BTreeMap<&Event, HashMap<Gender, u64>>) -> Vec<Gender> {
+    GENDERS
+        .into_iter()
+        .filter(|gender| {
+            matches!(gender, Female | Male)
+                || data
+                    .values()
+                    .any(|counts| counts.get(gender).is_some_and(|count| *count > 0))
+        })
+        .collect()
+}
+
+pub fn gender_color(gender: &Gender) -> RGBColor {
+    match gender {
+        Female => MAGENTA,
+        Male => BLUE,
+        NonBinary => GREEN,
+        Unspecified => RGBColor(128, 128, 128),
+    }
+}
+
 fn compute_upper_y_bound(data: &BTreeMap<&Event, HashMap<Gender, u64>>) -> i32 {
     let max_participants_count = compute_max_participants_count(data);
     (((max_participants_count + 10) / 10) * 10) as i32
@@ -135,31 +160,33 @@ where
 fn draw_chart<DB>(
     chart: &mut ChartContext<DB, Cartesian2d<RangedCoordf32, RangedCoordi32>>,
     data: &BTreeMap<&Event, HashMap<Gender, u64>>,
+    genders: &[Gender],
     events_count: usize,
 ) -> Result<()>
 where
     DB: DrawingBackend,
 {
+    let bars_count = genders.len();
     chart
         .draw_series(
             (0..events_count)
                 .zip(data.iter())
                 .flat_map(|(x, (event, counts))| {
-                    let x = x as f32 * 2.0;
-
-                    let (female_bar, count_female) =
-                        draw_bar(x, *counts.get(&Female).unwrap_or(&0), true, false, MAGENTA);
-                    let (male_bar, count_male) =
-                        draw_bar(x + 1.0, *counts.get(&Male).unwrap_or(&0), false, true, BLUE);
-                    let label = draw_label(x, event.name().as_str(), 2);
-
-                    vec![
-                        female_bar.into_dyn(),
-                        male_bar.into_dyn(),
-                        label.into_dyn(),
-                        count_female.into_dyn(),
-                        count_male.into_dyn(),
-                    ]
+                    let x = (x * bars_count) as f32;
+
+                    let mut elements = vec![draw_label(x, event.name().as_str(), bars_count).into_dyn()];
+                    for (i, gender) in genders.iter().enumerate() {
+                        let (bar, count) = draw_bar(
+                            x + i as f32,
+                            *counts.get(gender).unwrap_or(&0),
+                            i == 0,
+                            i == bars_count - 1,
+                            gender_color(gender),
+                        );
+                        elements.push(bar.into_dyn());
+                        elements.push(count.into_dyn());
+                    }
+                    elements
                 }),
         )
         .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
@@ -263,6 +290,28 @@ mod tests {
         }
     }
 
+    mod compute_displayed_genders {
+        use super::*;
+
+        #[test]
+        fn success_only_females_and_males() {
+            let convention = get_test_convention();
+            let data = group_by_gender_by_event(&convention);
+
+            assert_eq!(vec![Female, Male], compute_displayed_genders(&data));
+        }
+
+        #[test]
+        fn success_other_genders() {
+            let event = Event::new(0, "Flat - All".to_string());
+            let data: BTreeMap<&Event, HashMap<Gender, u64>> = [(&event, [(Male, 2), (Unspecified, 1)].into_iter().collect())]
+                .into_iter()
+                .collect();
+
+            assert_eq!(vec![Female, Male, Unspecified], compute_displayed_genders(&data));
+        }
+    }
+
     mod group_by_gender_by_event {
         use super::*;
 
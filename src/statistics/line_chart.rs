@@ -0,0 +1,84 @@
+//! Line charts of counts over the years.
+
+use super::error::Result;
+use crate::statistics::error::DrawingError;
+use plotters::prelude::*;
+use std::path::Path;
+
+/// Draw one line per series, each series giving a count for each year.
+/// Years without count are not drawn: a line may start after the first year, stop before the last one,
+/// or be broken in between.
+pub fn draw_line_chart(file: &Path, caption: &str, years: &[u16], series: &[(String, Vec<Option<usize>>)]) -> Result<()> {
+    let drawing_area = BitMapBackend::new(file, (2048, 1024)).into_drawing_area();
+    drawing_area
+        .fill(&WHITE)
+        .map_err(|e| DrawingError::DrawingArea(e.to_string()))?;
+
+    let first_year = years.first().copied().unwrap_or_default() as i32;
+    let last_year = years.last().copied().unwrap_or_default() as i32;
+    let max_count = series
+        .iter()
+        .flat_map(|(_, counts)| counts.iter())
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or_default();
+    let upper_y_bound = (((max_count + 10) / 10) * 10) as i32;
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(20)
+        .caption(caption, ("sans-serif", 40))
+        .set_label_area_size(LabelAreaPosition::Left, 60)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        // A single year still needs a range to be drawn
+        .build_cartesian_2d(first_year..last_year.max(first_year + 1), 0..upper_y_bound)
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+    chart
+        .configure_mesh()
+        .x_labels(years.len().max(2))
+        .x_label_formatter(&|year| year.to_string())
+        .label_style(("sans-serif", 20))
+        .draw()
+        .map_err(|e| DrawingError::ChartContext(e.to_string()))?;
+
+    for (index, (label, counts)) in series.iter().enumerate() {
+        let color = Palette99::pick(index).to_rgba();
+        let points: Vec<Option<(i32, i32)>> = years
+            .iter()
+            .zip(counts.iter())
+            .map(|(year, count)| count.map(|count| (*year as i32, count as i32)))
+            .collect();
+        // A year without count breaks the line, for the chart not to imply the event was held
+        let runs: Vec<Vec<(i32, i32)>> = points
+            .split(|point| point.is_none())
+            .filter(|run| !run.is_empty())
+            .map(|run| run.iter().flatten().copied().collect())
+            .collect();
+        for (run_index, run) in runs.iter().enumerate() {
+            let line = chart
+                .draw_series(LineSeries::new(run.clone(), color.stroke_width(3)))
+                .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+            if run_index == 0 {
+                line.label(label)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(3)));
+            }
+        }
+        chart
+            .draw_series(points.into_iter().flatten().map(|point| Circle::new(point, 5, color.filled())))
+            .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperRight)
+        .label_font(("sans-serif", 20))
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()
+        .map_err(|e| DrawingError::ChartDrawing(e.to_string()))?;
+    drawing_area
+        .present()
+        .map_err(|e| DrawingError::Presentation(e.to_string()))?;
+
+    Ok(())
+}
@@ -1,3 +1,18 @@
-mod gender_repartition;
+pub mod gender_repartition;
 pub mod error;
 pub mod events_registrants_dependency;
+pub mod team_rosters;
+pub mod participation_trends;
+pub mod line_chart;
+pub mod age_brackets;
+pub mod retention;
+pub mod age_distribution;
+pub mod age_pyramid;
+pub mod events_per_registrant;
+pub mod clubs;
+pub mod co_registration;
+pub mod clustering;
+pub mod co_registration_heatmap;
+pub mod schedule_conflicts;
+pub mod schedule_solver;
+pub mod event_families;
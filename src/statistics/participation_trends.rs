@@ -0,0 +1,295 @@
+//! How participation evolves from one year to the next.
+
+use super::error::Result;
+use crate::configuration::error::ConfigurationError::UnknownCategory;
+use crate::configuration::events_configuration::EventsConfiguration;
+use crate::configuration::events_mapping::canonical_event_category;
+use crate::error;
+use crate::registration::history::ConventionHistory;
+use crate::statistics::line_chart::draw_line_chart;
+use derive_getters::Getters;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Counts of each year of a history, in chronological order.
+#[derive(Debug, Getters, PartialEq)]
+pub struct ParticipationTrends {
+    years: Vec<u16>,
+    registrants: Vec<usize>,
+    /// Participations to the events of each category of the events configuration.
+    /// A registrant entering two events of a category counts twice.
+    categories: BTreeMap<String, Vec<usize>>,
+    /// Participants of each canonical event held at least once, nothing for the years it was not held.
+    events: BTreeMap<String, Vec<Option<usize>>>,
+}
+
+/// Count registrants and participations of every year of the history.
+/// Every canonical event has to belong to a category of the events configuration.
+pub fn compute_trends(
+    history: &ConventionHistory,
+    configuration: &EventsConfiguration,
+) -> error::Result<ParticipationTrends> {
+    let years: Vec<u16> = history.years().collect();
+    let mut registrants = vec![];
+    let mut categories: BTreeMap<String, Vec<usize>> = configuration
+        .categories()
+        .keys()
+        .map(|category| (category.clone(), vec![0; years.len()]))
+        .collect();
+    let mut events: BTreeMap<String, Vec<Option<usize>>> = BTreeMap::new();
+
+    for (index, (_, convention)) in history.conventions().enumerate() {
+        registrants.push(convention.registrations().len());
+        for event in convention.events() {
+            let participants = convention.participants_by_event()[*event.index()].len();
+            let category = canonical_event_category(event.name()).unwrap_or(event.name());
+            let category_counts = categories
+                .get_mut(category)
+                .ok_or_else(|| UnknownCategory(category.to_string()))?;
+            category_counts[index] += participants;
+            let event_counts = events.entry(event.name().clone()).or_insert_with(|| vec![None; years.len()]);
+            *event_counts[index].get_or_insert(0) += participants;
+        }
+    }
+
+    Ok(ParticipationTrends {
+        years,
+        registrants,
+        categories,
+        events,
+    })
+}
+
+/// Relative change from each year to the next one, in percent.
+/// Nothing can be said about a change from zero, or from or to a year without count.
+pub fn compute_growth_rates(counts: &[Option<usize>]) -> Vec<Option<f32>> {
+    counts
+        .windows(2)
+        .map(|counts| match (counts[0], counts[1]) {
+            (None, _) | (_, None) | (Some(0), _) => None,
+            (Some(previous), Some(next)) => Some((next as f32 - previous as f32) / previous as f32 * 100.0),
+        })
+        .collect()
+}
+
+/// Export the trends as a semicolon separated table:
+/// one line for the registrants, then one per category and one per canonical event,
+/// with the count of each year followed by the growth rate between each year and the next one.
+/// Years an event was not held are left empty.
+pub fn generate_csv_file(trends: &ParticipationTrends) -> String {
+    let years = &trends.years;
+    let mut content = format!(
+        ";{};{}",
+        years.iter().map(u16::to_string).collect::<Vec<_>>().join(";"),
+        years
+            .windows(2)
+            .map(|years| format!("{}-{}", years[0], years[1]))
+            .collect::<Vec<_>>()
+            .join(";")
+    );
+
+    let lines = std::iter::once(("registrants", held_every_year(&trends.registrants)))
+        .chain(trends.categories.iter().map(|(category, counts)| (category.as_str(), held_every_year(counts))))
+        .chain(trends.events.iter().map(|(event, counts)| (event.as_str(), counts.clone())));
+    for (name, counts) in lines {
+        let counts_cells = counts
+            .iter()
+            .map(|count| count.map(|count| count.to_string()).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(";");
+        let growth_cells = compute_growth_rates(&counts)
+            .iter()
+            .map(|rate| rate.map(|rate| format!("{rate:+.2}%")).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(";");
+        content = format!("{content}\n{name};{counts_cells};{growth_cells}");
+    }
+
+    content
+}
+
+/// Draw the trends as line charts, saved in given folder:
+/// - `trends.png` for the registrants and each category,
+/// - `trends-<category>.png` for the events of each category.
+pub fn draw_and_export_graphs(
+    trends: &ParticipationTrends,
+    configuration: &EventsConfiguration,
+    folder: &Path,
+) -> Result<()> {
+    let category_name = |category: &String| {
+        configuration
+            .categories()
+            .get(category)
+            .map(|category| category.name().clone())
+            .unwrap_or_else(|| category.clone())
+    };
+
+    let series: Vec<(String, Vec<Option<usize>>)> = std::iter::once(("Inscrits".to_string(), &trends.registrants))
+        .chain(trends.categories.iter().map(|(category, counts)| (category_name(category), counts)))
+        .map(|(name, counts)| (name, held_every_year(counts)))
+        .collect();
+    draw_line_chart(&folder.join("trends.png"), "Participation par catégorie", &trends.years, &series)?;
+
+    for category in trends.categories.keys() {
+        let series: Vec<(String, Vec<Option<usize>>)> = trends
+            .events
+            .iter()
+            .filter(|(event, _)| canonical_event_category(event) == Some(category.as_str()))
+            .map(|(event, counts)| (event_name(configuration, event), counts.clone()))
+            .collect();
+        if series.is_empty() {
+            continue;
+        }
+        let caption = format!("Participation par épreuve ({})", category_name(category));
+        draw_line_chart(&folder.join(format!("trends-{category}.png")), &caption, &trends.years, &series)?;
+    }
+
+    Ok(())
+}
+
+/// Registrants and categories are counted every year, even when nobody registered.
+fn held_every_year(counts: &[usize]) -> Vec<Option<usize>> {
+    counts.iter().copied().map(Some).collect()
+}
+
+/// Display name of a canonical event, as given by the events configuration.
+fn event_name(configuration: &EventsConfiguration, id: &str) -> String {
+    id.split_once('/')
+        .and_then(|(category, event)| configuration.categories().get(category)?.events().get(event))
+        .cloned()
+        .unwrap_or_else(|| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    mod compute_trends {
+        use crate::configuration::events_configuration::{load_configuration, EventsCategory, EventsConfiguration};
+        use crate::error::ApplicationError;
+        use crate::registration::convention::Convention;
+        use crate::registration::event::Event;
+        use crate::registration::gender::Gender;
+        use crate::registration::history::ConventionHistory;
+        use crate::statistics::participation_trends::compute_trends;
+        use crate::test_data::{get_test_history, get_test_registrant};
+        use std::collections::BTreeMap;
+        use std::path::PathBuf;
+
+        fn get_events_configuration() -> EventsConfiguration {
+            load_configuration(&PathBuf::from(format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR")))).unwrap()
+        }
+
+        #[test]
+        fn success() {
+            let history = get_test_history();
+
+            let trends = compute_trends(&history, &get_events_configuration()).unwrap();
+
+            assert_eq!(&vec![2016, 2017], trends.years());
+            assert_eq!(&vec![50, 30], trends.registrants());
+            let convention = history.get(2016).unwrap();
+            let event = convention.events().iter().find(|event| event.name() == "courses-sur-route/marathon-illimite").unwrap();
+            assert_eq!(
+                Some(convention.participants_by_event()[*event.index()].len()),
+                trends.events()["courses-sur-route/marathon-illimite"][0]
+            );
+            let route_participations: usize = convention
+                .events()
+                .iter()
+                .filter(|event| event.name().starts_with("courses-sur-route/"))
+                .map(|event| convention.participants_by_event()[*event.index()].len())
+                .sum();
+            assert_eq!(route_participations, trends.categories()["courses-sur-route"][0]);
+        }
+
+        #[test]
+        fn success_event_not_held() {
+            let registrant = get_test_registrant(1, 25, Gender::Female, None);
+            let events = |names: &[&str]| names.iter().enumerate().map(|(index, name)| Event::new(index, name.to_string())).collect();
+            let (ten_kilometers, marathon) = ("courses-sur-route/10km-standard", "courses-sur-route/marathon-standard");
+            let history = ConventionHistory::new(BTreeMap::from([
+                (2016, Convention::build(vec![(registrant.clone(), vec![0])], vec![vec![]], events(&[ten_kilometers, marathon]))),
+                // The marathon was not held in 2017
+                (2017, Convention::build(vec![(registrant, vec![0])], vec![vec![]], events(&[ten_kilometers]))),
+            ]));
+
+            let trends = compute_trends(&history, &get_events_configuration()).unwrap();
+
+            assert_eq!(vec![Some(1), Some(1)], trends.events()[ten_kilometers]);
+            assert_eq!(vec![Some(0), None], trends.events()[marathon]);
+        }
+
+        #[test]
+        fn fail_unknown_category() {
+            let configuration = EventsConfiguration::new(
+                [("route".to_string(), EventsCategory::new("Route".to_string(), Default::default()))]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let error = compute_trends(&get_test_history(), &configuration).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::Configuration(_)));
+        }
+    }
+
+    mod compute_growth_rates {
+        use crate::statistics::participation_trends::compute_growth_rates;
+
+        #[test]
+        fn success() {
+            assert_eq!(vec![Some(50.0), Some(-100.0), None], compute_growth_rates(&[Some(10), Some(15), Some(0), Some(4)]));
+            assert!(compute_growth_rates(&[Some(4)]).is_empty());
+        }
+
+        #[test]
+        fn success_missing_year() {
+            assert_eq!(vec![Some(50.0), None, None], compute_growth_rates(&[Some(10), Some(15), None, Some(4)]));
+        }
+    }
+
+    mod generate_csv_file {
+        use crate::statistics::participation_trends::{generate_csv_file, ParticipationTrends};
+
+        #[test]
+        fn success() {
+            let trends = ParticipationTrends {
+                years: vec![2016, 2017],
+                registrants: vec![40, 50],
+                categories: [("route".to_string(), vec![20, 15])].into_iter().collect(),
+                events: [
+                    ("route/10km".to_string(), vec![Some(0), Some(15)]),
+                    ("route/marathon".to_string(), vec![None, Some(15)]),
+                ]
+                .into_iter()
+                .collect(),
+            };
+
+            assert_eq!(
+                ";2016;2017;2016-2017\nregistrants;40;50;+25.00%\nroute;20;15;-25.00%\nroute/10km;0;15;\nroute/marathon;;15;",
+                generate_csv_file(&trends)
+            );
+        }
+    }
+
+    mod draw_and_export_graphs {
+        use crate::configuration::events_configuration::load_configuration;
+        use crate::statistics::participation_trends::{compute_trends, draw_and_export_graphs};
+        use crate::test_data::get_test_history;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+        use std::path::PathBuf;
+
+        #[test]
+        fn success() {
+            let folder = temp_dir().join("participation-trends");
+            create_dir_all(&folder).unwrap();
+            let configuration = load_configuration(&PathBuf::from(format!("{}/configuration/events.yml", env!("CARGO_MANIFEST_DIR")))).unwrap();
+            let trends = compute_trends(&get_test_history(), &configuration).unwrap();
+
+            draw_and_export_graphs(&trends, &configuration, &folder).unwrap();
+
+            assert!(folder.join("trends.png").exists());
+            assert!(folder.join("trends-courses-sur-route.png").exists());
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! Whether riders come back from one year to the next.
+
+use super::error::Result;
+use crate::registration::gender::Gender;
+use crate::registration::history::ConventionHistory;
+use crate::registration::identity::RiderIdentities;
+use crate::registration::registrant::Registrant;
+use crate::statistics::age_brackets::AgeBrackets;
+use crate::statistics::line_chart::draw_line_chart;
+use derive_getters::Getters;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Comings and goings of riders in a year of the history.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct YearRetention {
+    year: u16,
+    riders: usize,
+    /// Riders registering for the first time of the history.
+    first_timers: usize,
+    /// Riders registering again the following year. Unknown for the last year, or when the history lacks the following year.
+    retained: Option<usize>,
+}
+
+/// Riders who first registered the same year.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct Cohort {
+    first_year: u16,
+    /// Riders of the cohort registered each year of the history, from the first year on.
+    survivors: Vec<usize>,
+}
+
+/// Riders of a year, gender and age bracket who did not come back the following year.
+/// Years whose following year the history lacks have no churn.
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct Churn {
+    year: u16,
+    /// Index of the riders' age bracket, see [AgeBrackets::label].
+    bracket: usize,
+    gender: Gender,
+    riders: usize,
+    churned: usize,
+}
+
+#[derive(Debug, Getters, Clone, PartialEq, Eq)]
+pub struct RetentionReport {
+    years: Vec<YearRetention>,
+    cohorts: Vec<Cohort>,
+    /// Sorted by year, age bracket, then gender. The last year of the history has no churn.
+    churn: Vec<Churn>,
+}
+
+/// Follow the riders of the history from one year to the next.
+/// A rider's age bracket and gender are the ones of the year they may not have come back from.
+///
+/// Registrations sharing the id of a previous registration of the same year resolve to the same rider:
+/// only the first one counts, the others being reported by [crate::registration::identity::IdentityResolver::resolve].
+pub fn compute_retention(
+    history: &ConventionHistory,
+    identities: &RiderIdentities,
+    brackets: &AgeBrackets,
+) -> RetentionReport {
+    let riders_by_year: Vec<(u16, BTreeMap<usize, &Registrant>)> = history
+        .conventions()
+        .map(|(year, convention)| {
+            let mut riders = BTreeMap::new();
+            for (registrant, _) in convention.registrations() {
+                if let Some(rider) = identities.rider(year, registrant) {
+                    riders.entry(rider).or_insert(registrant);
+                }
+            }
+            (year, riders)
+        })
+        .collect();
+
+    let mut first_years: HashMap<usize, u16> = HashMap::new();
+    for (year, riders) in &riders_by_year {
+        for rider in riders.keys() {
+            first_years.entry(*rider).or_insert(*year);
+        }
+    }
+
+    let mut years = vec![];
+    let mut cohorts = vec![];
+    let mut churn = vec![];
+    for (index, (year, riders)) in riders_by_year.iter().enumerate() {
+        // Riders coming back after skipping a year are not retained year over year
+        let next_riders = riders_by_year
+            .get(index + 1)
+            .filter(|(next_year, _)| *next_year == year + 1)
+            .map(|(_, riders)| riders);
+        let first_timers: Vec<usize> = riders
+            .keys()
+            .filter(|rider| first_years[rider] == *year)
+            .copied()
+            .collect();
+
+        years.push(YearRetention {
+            year: *year,
+            riders: riders.len(),
+            first_timers: first_timers.len(),
+            retained: next_riders.map(|next_riders| riders.keys().filter(|rider| next_riders.contains_key(rider)).count()),
+        });
+
+        if !first_timers.is_empty() {
+            cohorts.push(Cohort {
+                first_year: *year,
+                survivors: riders_by_year[index..]
+                    .iter()
+                    .map(|(_, riders)| first_timers.iter().filter(|rider| riders.contains_key(rider)).count())
+                    .collect(),
+            });
+        }
+
+        if let Some(next_riders) = next_riders {
+            let mut groups: BTreeMap<(usize, Gender), (usize, usize)> = BTreeMap::new();
+            for (rider, registrant) in riders {
+                let group = groups
+                    .entry((brackets.bracket(*registrant.age()), registrant.gender().clone()))
+                    .or_default();
+                group.0 += 1;
+                if !next_riders.contains_key(rider) {
+                    group.1 += 1;
+                }
+            }
+            churn.extend(groups.into_iter().map(|((bracket, gender), (riders, churned))| Churn {
+                year: *year,
+                bracket,
+                gender,
+                riders,
+                churned,
+            }));
+        }
+    }
+
+    RetentionReport { years, cohorts, churn }
+}
+
+/// Export the riders, first-timers and retained riders of each year as a semicolon separated table.
+pub fn generate_retention_csv_file(report: &RetentionReport) -> String {
+    let mut content = "Year;Riders;First-timers;Retained;Retention rate".to_string();
+    for year in &report.years {
+        let (retained, rate) = match year.retained {
+            Some(retained) => (retained.to_string(), percentage(retained, year.riders)),
+            None => (String::new(), String::new()),
+        };
+        content = format!("{content}\n{};{};{};{retained};{rate}", year.year, year.riders, year.first_timers);
+    }
+
+    content
+}
+
+/// Export the survivors of each cohort, one line per cohort and one column per year.
+pub fn generate_cohorts_csv_file(report: &RetentionReport) -> String {
+    let years: Vec<u16> = report.years.iter().map(|year| year.year).collect();
+    let mut content = format!(
+        "First year;{}",
+        years.iter().map(u16::to_string).collect::<Vec<_>>().join(";")
+    );
+    for cohort in &report.cohorts {
+        let skipped_years = years.iter().take_while(|year| **year < cohort.first_year).count();
+        let cells: Vec<String> = std::iter::repeat_n(String::new(), skipped_years)
+            .chain(cohort.survivors.iter().map(usize::to_string))
+            .collect();
+        content = format!("{content}\n{};{}", cohort.first_year, cells.join(";"));
+    }
+
+    content
+}
+
+/// Export the churn of each year, age bracket and gender as a semicolon separated table.
+pub fn generate_churn_csv_file(report: &RetentionReport, brackets: &AgeBrackets) -> String {
+    let mut content = "Year;Age;Gender;Riders;Churned;Churn rate".to_string();
+    for churn in &report.churn {
+        content = format!(
+            "{content}\n{};{};{};{};{};{}",
+            churn.year,
+            brackets.label(churn.bracket),
+            churn.gender,
+            churn.riders,
+            churn.churned,
+            percentage(churn.churned, churn.riders)
+        );
+    }
+
+    content
+}
+
+/// Draw the share of each cohort still registering each year, saved as `cohorts.png` in given folder.
+pub fn draw_and_export_survival_curves(report: &RetentionReport, folder: &Path) -> Result<()> {
+    let years: Vec<u16> = report.years.iter().map(|year| year.year).collect();
+    let series: Vec<(String, Vec<Option<usize>>)> = report
+        .cohorts
+        .iter()
+        .map(|cohort| {
+            let size = cohort.survivors.first().copied().unwrap_or_default().max(1);
+            let skipped_years = years.iter().take_while(|year| **year < cohort.first_year).count();
+            let shares = std::iter::repeat_n(None, skipped_years)
+                .chain(cohort.survivors.iter().map(|survivors| Some(survivors * 100 / size)))
+                .collect();
+            (format!("Cohorte {}", cohort.first_year), shares)
+        })
+        .collect();
+
+    draw_line_chart(&folder.join("cohorts.png"), "Fidélité des cohortes (%)", &years, &series)
+}
+
+fn percentage(count: usize, total: usize) -> String {
+    if total == 0 {
+        String::new()
+    } else {
+        format!("{:.2}%", count as f32 / total as f32 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod compute_retention {
+        use crate::registration::gender::Gender;
+        use crate::registration::identity::IdentityResolver;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::registration::convention::Convention;
+        use crate::registration::history::ConventionHistory;
+        use crate::statistics::retention::{compute_retention, Churn, Cohort, YearRetention};
+        use crate::test_data::{get_test_history, get_test_registrant};
+        use std::collections::BTreeMap;
+
+        #[test]
+        fn success() {
+            let history = get_test_history();
+            let identities = IdentityResolver::default().resolve(&history).0;
+
+            let report = compute_retention(&history, &identities, &AgeBrackets::default());
+
+            assert_eq!(
+                &vec![
+                    YearRetention { year: 2016, riders: 50, first_timers: 50, retained: Some(29) },
+                    YearRetention { year: 2017, riders: 30, first_timers: 1, retained: None },
+                ],
+                report.years()
+            );
+            assert_eq!(
+                &vec![
+                    Cohort { first_year: 2016, survivors: vec![50, 29] },
+                    Cohort { first_year: 2017, survivors: vec![1] },
+                ],
+                report.cohorts()
+            );
+            assert!(report.churn().iter().all(|churn| *churn.year() == 2016));
+            assert_eq!(50, report.churn().iter().map(|churn| churn.riders()).sum::<usize>());
+            assert_eq!(21, report.churn().iter().map(|churn| churn.churned()).sum::<usize>());
+            let victor = report.churn().iter().find(|churn| *churn.bracket() == 6 && *churn.gender() == Gender::Male).unwrap();
+            assert!(victor.churned() < victor.riders());
+        }
+
+        #[test]
+        fn success_missing_year() {
+            let registrant = || (get_test_registrant(1, 16, Gender::Female, None), vec![]);
+            let conventions = BTreeMap::from([
+                (2016, Convention::new(vec![registrant()], vec![vec![]], vec![], vec![])),
+                (2018, Convention::new(vec![registrant()], vec![vec![]], vec![], vec![])),
+            ]);
+            let history = ConventionHistory::new(conventions);
+            let identities = IdentityResolver::default().resolve(&history).0;
+
+            let report = compute_retention(&history, &identities, &AgeBrackets::default());
+
+            assert_eq!(
+                &vec![
+                    YearRetention { year: 2016, riders: 1, first_timers: 1, retained: None },
+                    YearRetention { year: 2018, riders: 1, first_timers: 0, retained: None },
+                ],
+                report.years()
+            );
+            assert_eq!(&vec![Cohort { first_year: 2016, survivors: vec![1, 1] }], report.cohorts());
+            assert!(report.churn().is_empty());
+        }
+
+        #[test]
+        fn success_duplicate_id() {
+            let registrations = vec![
+                (get_test_registrant(1, 16, Gender::Female, None), vec![]),
+                (get_test_registrant(1, 30, Gender::Male, None), vec![]),
+            ];
+            let conventions = BTreeMap::from([
+                (2016, Convention::new(registrations, vec![vec![]; 2], vec![], vec![])),
+                (2017, Convention::new(vec![], vec![], vec![], vec![])),
+            ]);
+            let history = ConventionHistory::new(conventions);
+            let identities = IdentityResolver::default().resolve(&history).0;
+
+            let report = compute_retention(&history, &identities, &AgeBrackets::default());
+
+            assert_eq!(1, report.years()[0].riders);
+            assert_eq!(
+                &vec![Churn { year: 2016, bracket: AgeBrackets::default().bracket(16), gender: Gender::Female, riders: 1, churned: 1 }],
+                report.churn()
+            );
+        }
+    }
+
+    mod generate_retention_csv_file {
+        use crate::statistics::retention::{generate_retention_csv_file, RetentionReport, YearRetention};
+
+        #[test]
+        fn success() {
+            let report = RetentionReport {
+                years: vec![
+                    YearRetention { year: 2016, riders: 40, first_timers: 40, retained: Some(10) },
+                    YearRetention { year: 2017, riders: 30, first_timers: 20, retained: None },
+                ],
+                cohorts: vec![],
+                churn: vec![],
+            };
+
+            assert_eq!(
+                "Year;Riders;First-timers;Retained;Retention rate\n2016;40;40;10;25.00%\n2017;30;20;;",
+                generate_retention_csv_file(&report)
+            );
+        }
+    }
+
+    mod generate_cohorts_csv_file {
+        use crate::statistics::retention::{generate_cohorts_csv_file, Cohort, RetentionReport, YearRetention};
+
+        #[test]
+        fn success() {
+            let report = RetentionReport {
+                years: vec![
+                    YearRetention { year: 2016, riders: 40, first_timers: 40, retained: Some(10) },
+                    YearRetention { year: 2017, riders: 30, first_timers: 20, retained: None },
+                ],
+                cohorts: vec![
+                    Cohort { first_year: 2016, survivors: vec![40, 10] },
+                    Cohort { first_year: 2017, survivors: vec![20] },
+                ],
+                churn: vec![],
+            };
+
+            assert_eq!("First year;2016;2017\n2016;40;10\n2017;;20", generate_cohorts_csv_file(&report));
+        }
+    }
+
+    mod generate_churn_csv_file {
+        use crate::registration::gender::Gender;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::retention::{generate_churn_csv_file, Churn, RetentionReport};
+
+        #[test]
+        fn success() {
+            let report = RetentionReport {
+                years: vec![],
+                cohorts: vec![],
+                churn: vec![Churn { year: 2016, bracket: 1, gender: Gender::NonBinary, riders: 4, churned: 1 }],
+            };
+
+            assert_eq!(
+                "Year;Age;Gender;Riders;Churned;Churn rate\n2016;11-12;Non-binary;4;1;25.00%",
+                generate_churn_csv_file(&report, &AgeBrackets::default())
+            );
+        }
+    }
+
+    mod draw_and_export_survival_curves {
+        use crate::registration::identity::IdentityResolver;
+        use crate::statistics::age_brackets::AgeBrackets;
+        use crate::statistics::retention::{compute_retention, draw_and_export_survival_curves};
+        use crate::test_data::get_test_history;
+        use std::env::temp_dir;
+        use std::fs::create_dir_all;
+
+        #[test]
+        fn success() {
+            let folder = temp_dir().join("retention");
+            create_dir_all(&folder).unwrap();
+            let history = get_test_history();
+            let identities = IdentityResolver::default().resolve(&history).0;
+            let report = compute_retention(&history, &identities, &AgeBrackets::default());
+
+            draw_and_export_survival_curves(&report, &folder).unwrap();
+
+            assert!(folder.join("cohorts.png").exists());
+        }
+    }
+}
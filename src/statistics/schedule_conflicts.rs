@@ -0,0 +1,282 @@
+//! Riders who cannot make it to two events of the programme.
+
+use crate::configuration::programme::{Programme, Slot};
+use crate::error;
+use crate::error::ApplicationError::{AmbiguousEvent, UnknownEvent};
+use crate::registration::convention::Convention;
+use crate::registration::registrant::Registrant;
+use derive_getters::Getters;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConflictKind {
+    /// Both slots take place at the same time.
+    Overlap,
+    /// The second slot starts too soon after the first one ends for a rider to get there.
+    BackToBack,
+}
+
+/// Two slots of different events sharing riders who cannot attend both.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct Conflict {
+    /// The slot starting first.
+    first: Slot,
+    second: Slot,
+    kind: ConflictKind,
+    /// Minutes between the end of the first slot and the start of the second one, negative when they overlap.
+    gap: i64,
+    /// Minutes riders need between both slots, see [Programme::required_break].
+    required_break: u32,
+    /// Riders registered to both events.
+    riders: Vec<Registrant>,
+}
+
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct ScheduleReport {
+    /// Sorted from the conflict affecting the most riders to the one affecting the fewest,
+    /// then in chronological order.
+    conflicts: Vec<Conflict>,
+    /// Events of the convention without any slot in the programme.
+    unscheduled: Vec<String>,
+}
+
+/// Find every couple of slots of different events that are too close for their shared riders:
+/// overlapping, or separated by less than the break riders need, including the transfer between venues.
+/// Slots ending exactly when the other one starts are back-to-back, unless no break is required.
+///
+/// Slots refer to events by their canonical `category/event` id, see [Convention::normalize]:
+/// every event of the programme has to be a single event of the convention.
+pub fn find_conflicts(convention: &Convention, programme: &Programme) -> error::Result<ScheduleReport> {
+    let mut slots: Vec<&Slot> = programme.slots().iter().collect();
+    slots.sort_by_key(|slot| (*slot.day(), *slot.start(), *slot.end()));
+    let slot_participants = slots
+        .iter()
+        .map(|slot| {
+            let event = find_event(convention, slot.event())?;
+            Ok(convention.participants_by_event()[event].iter().collect())
+        })
+        .collect::<error::Result<Vec<HashSet<&Registrant>>>>()?;
+
+    let mut conflicts = vec![];
+    for (index, first) in slots.iter().enumerate() {
+        for (second, second_participants) in slots.iter().zip(&slot_participants).skip(index + 1) {
+            if first.event() == second.event() || first.day() != second.day() {
+                continue;
+            }
+
+            let gap = (*second.start() - *first.end()).num_minutes();
+            let required_break = programme.required_break(first.venue(), second.venue());
+            let kind = if gap < 0 {
+                ConflictKind::Overlap
+            } else if gap < required_break as i64 {
+                ConflictKind::BackToBack
+            } else {
+                continue;
+            };
+
+            let mut riders: Vec<Registrant> = slot_participants[index]
+                .intersection(second_participants)
+                .map(|registrant| (*registrant).clone())
+                .collect();
+            if riders.is_empty() {
+                continue;
+            }
+            riders.sort_by_key(|rider| *rider.id());
+
+            conflicts.push(Conflict {
+                first: (*first).clone(),
+                second: (*second).clone(),
+                kind,
+                gap,
+                required_break,
+                riders,
+            });
+        }
+    }
+    // Stable: conflicts affecting as many riders stay in chronological order
+    conflicts.sort_by_key(|conflict| std::cmp::Reverse(conflict.riders.len()));
+
+    let scheduled: HashSet<&String> = slots.iter().map(|slot| slot.event()).collect();
+    let mut unscheduled: Vec<String> = convention
+        .events()
+        .iter()
+        .map(|event| event.name())
+        .filter(|name| !scheduled.contains(name))
+        .cloned()
+        .collect();
+    unscheduled.sort();
+    unscheduled.dedup();
+
+    Ok(ScheduleReport {
+        conflicts,
+        unscheduled,
+    })
+}
+
+/// Index of the event of the convention with the given name, which should be its canonical id.
+/// Raw headers may be shared by several events, e.g. `Parcours IUF - All`: they are rejected as ambiguous.
+pub fn find_event(convention: &Convention, name: &str) -> error::Result<usize> {
+    let mut events = convention.events().iter().filter(|event| event.name() == name);
+    match (events.next(), events.next()) {
+        (Some(event), None) => Ok(*event.index()),
+        (Some(_), Some(_)) => Err(AmbiguousEvent(name.to_string())),
+        (None, _) => Err(UnknownEvent(name.to_string())),
+    }
+}
+
+impl ScheduleReport {
+    /// Riders affected by at least one conflict, sorted by id.
+    pub fn riders(&self) -> Vec<&Registrant> {
+        let mut riders: Vec<&Registrant> = self.conflicts.iter().flat_map(|conflict| conflict.riders.iter()).collect();
+        riders.sort_by_key(|rider| *rider.id());
+        riders.dedup_by_key(|rider| *rider.id());
+        riders
+    }
+}
+
+/// Export the conflicts as a semicolon separated table, one line per conflict with the riders affected.
+pub fn generate_csv_file(report: &ScheduleReport) -> String {
+    let mut content = "First event;First slot;Second event;Second slot;Conflict;Gap;Required break;Riders count;Riders".to_string();
+    for conflict in &report.conflicts {
+        let kind = match conflict.kind {
+            ConflictKind::Overlap => "Overlap",
+            ConflictKind::BackToBack => "Back-to-back",
+        };
+        let riders = conflict
+            .riders
+            .iter()
+            .map(|rider| format!("{} {}", rider.first_name(), rider.last_name()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        content = format!(
+            "{content}\n{};{};{};{};{kind};{};{};{};{riders}",
+            conflict.first.event(),
+            format_slot(&conflict.first),
+            conflict.second.event(),
+            format_slot(&conflict.second),
+            conflict.gap,
+            conflict.required_break,
+            conflict.riders.len()
+        );
+    }
+
+    content
+}
+
+/// E.g. `2016-05-05 09:00-10:30 (Stade)`.
+fn format_slot(slot: &Slot) -> String {
+    format!(
+        "{} {}-{} ({})",
+        slot.day(),
+        slot.start().format("%H:%M"),
+        slot.end().format("%H:%M"),
+        slot.venue()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    mod find_conflicts {
+        use crate::configuration::programme::{load_programme, Programme, Slot};
+        use crate::error::ApplicationError;
+        use crate::registration::registrant::Registrant;
+        use crate::statistics::schedule_conflicts::{find_conflicts, ConflictKind};
+        use crate::registration::convention::Convention;
+        use crate::statistics::schedule_conflicts::find_event;
+        use crate::test_data::{get_test_asset, get_test_convention, get_test_normalized_convention};
+        use chrono::{NaiveDate, NaiveTime};
+        use std::collections::HashSet;
+
+        fn shared_riders(convention: &Convention, first: &str, second: &str) -> usize {
+            let participants = |event: &str| &convention.participants_by_event()[find_event(convention, event).unwrap()];
+            let first: HashSet<&Registrant> = participants(first).iter().collect();
+            participants(second).iter().filter(|registrant| first.contains(registrant)).count()
+        }
+
+        fn slot(event: &str) -> Slot {
+            let time = |hour: u32| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+            Slot::new(event.to_string(), NaiveDate::from_ymd_opt(2016, 5, 5).unwrap(), time(9), time(10), "Stade".to_string())
+        }
+
+        #[test]
+        fn success() {
+            let convention = get_test_normalized_convention();
+            let programme = load_programme(&get_test_asset("configuration/programme.yml")).unwrap();
+
+            let report = find_conflicts(&convention, &programme).unwrap();
+
+            // 100m and the relay overlap, the relay ends 10 minutes before Trial starts in another venue
+            assert_eq!(2, report.conflicts().len());
+            let overlap = report.conflicts().iter().find(|conflict| *conflict.kind() == ConflictKind::Overlap).unwrap();
+            assert_eq!("athletisme/100m", overlap.first().event());
+            assert_eq!("athletisme/4x100m", overlap.second().event());
+            assert_eq!(-30, *overlap.gap());
+            assert_eq!(shared_riders(&convention, "athletisme/100m", "athletisme/4x100m"), overlap.riders().len());
+            let back_to_back = report.conflicts().iter().find(|conflict| *conflict.kind() == ConflictKind::BackToBack).unwrap();
+            assert_eq!("urbain/trial", back_to_back.second().event());
+            assert_eq!((10, 20), (*back_to_back.gap(), *back_to_back.required_break()));
+            assert_eq!(shared_riders(&convention, "athletisme/4x100m", "urbain/trial"), back_to_back.riders().len());
+            assert!(report.riders().len() <= overlap.riders().len() + back_to_back.riders().len());
+            assert!(report.riders().len() >= overlap.riders().len());
+            assert!(report.unscheduled().contains(&"sports-collectifs/hockey".to_string()));
+            assert!(!report.unscheduled().contains(&"urbain/trial".to_string()));
+        }
+
+        #[test]
+        fn fail_unknown_event() {
+            let programme = Programme::new(vec![slot("urbain/unknown")], vec![], 0);
+
+            let error = find_conflicts(&get_test_normalized_convention(), &programme).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::UnknownEvent(event) if event == "urbain/unknown"));
+        }
+
+        #[test]
+        fn fail_ambiguous_event() {
+            // Two columns of the raw export share this header, one for the challenge and one for athletics
+            let programme = Programme::new(vec![slot("Parcours IUF - All")], vec![], 0);
+
+            let error = find_conflicts(&get_test_convention(), &programme).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::AmbiguousEvent(event) if event == "Parcours IUF - All"));
+        }
+    }
+
+    mod generate_csv_file {
+        use crate::configuration::programme::Slot;
+        use crate::registration::gender::Gender;
+        use crate::statistics::schedule_conflicts::{generate_csv_file, Conflict, ConflictKind, ScheduleReport};
+        use crate::test_data::get_test_registrant;
+        use chrono::{NaiveDate, NaiveTime};
+
+        #[test]
+        fn success() {
+            let slot = |event: &str, start: u32, end: u32| {
+                Slot::new(
+                    event.to_string(),
+                    NaiveDate::from_ymd_opt(2016, 5, 5).unwrap(),
+                    NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                    NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+                    "Stade".to_string(),
+                )
+            };
+            let rider = get_test_registrant(1, 16, Gender::Female, None);
+            let report = ScheduleReport {
+                conflicts: vec![Conflict {
+                    first: slot("100m - All", 9, 10),
+                    second: slot("400m - All", 10, 11),
+                    kind: ConflictKind::BackToBack,
+                    gap: 0,
+                    required_break: 5,
+                    riders: vec![rider],
+                }],
+                unscheduled: vec![],
+            };
+
+            assert_eq!(
+                "First event;First slot;Second event;Second slot;Conflict;Gap;Required break;Riders count;Riders\n100m - All;2016-05-05 09:00-10:00 (Stade);400m - All;2016-05-05 10:00-11:00 (Stade);Back-to-back;0;5;1;Alex Martin 1",
+                generate_csv_file(&report)
+            );
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Suggest a programme keeping as few riders as possible from attending all their events.
+
+use crate::configuration::error::ConfigurationError::UnschedulableEvent;
+use crate::configuration::planning::Planning;
+use crate::configuration::programme::{Programme, Slot};
+use crate::error;
+use crate::registration::convention::Convention;
+use crate::statistics::schedule_conflicts::find_event;
+
+/// Assign each event of the planning to a slot of its own, respecting the constraints,
+/// while minimising the riders registered to two events they cannot both attend (see
+/// [crate::statistics::schedule_conflicts::find_conflicts]), then the number of such couples of events.
+///
+/// A first assignment is improved by moving events to free slots or swapping them, `iterations` times.
+/// Moves are drawn from the seed: the same seed always gives the same programme.
+///
+/// Events and constraints refer to events of the convention by their canonical id, see [find_event].
+/// Constraints of events not to schedule are ignored.
+pub fn suggest_programme(convention: &Convention, planning: &Planning, seed: u64, iterations: usize) -> error::Result<Programme> {
+    let events: Vec<&String> = match planning.events().is_empty() {
+        true => convention.events().iter().map(|event| event.name()).collect(),
+        false => planning.events().iter().collect(),
+    };
+    let indexes = events
+        .iter()
+        .map(|event| find_event(convention, event))
+        .collect::<error::Result<Vec<usize>>>()?;
+    for constraint in planning.constraints() {
+        find_event(convention, constraint.event())?;
+    }
+
+    let slots = planning.slots();
+    let allowed: Vec<Vec<usize>> = events
+        .iter()
+        .map(|event| {
+            (0..slots.len())
+                .filter(|slot| {
+                    planning
+                        .constraints()
+                        .iter()
+                        .filter(|constraint| constraint.event() == *event)
+                        .all(|constraint| constraint.allows(&slots[*slot]))
+                })
+                .collect()
+        })
+        .collect();
+
+    let rider_events: Vec<Vec<usize>> = convention
+        .registrations()
+        .iter()
+        .map(|(_, registered_events)| {
+            registered_events
+                .iter()
+                .filter_map(|event| indexes.iter().position(|index| index == event))
+                .collect()
+        })
+        .filter(|registered_events: &Vec<usize>| registered_events.len() > 1)
+        .collect();
+
+    let programme = Programme::new(vec![], planning.transfer_times().clone(), *planning.min_break());
+    let clashes: Vec<Vec<bool>> = slots
+        .iter()
+        .map(|first| {
+            slots
+                .iter()
+                .map(|second| {
+                    let (earlier, later) = match first.start() <= second.start() {
+                        true => (first, second),
+                        false => (second, first),
+                    };
+                    let gap = (*later.start() - *earlier.end()).num_minutes();
+                    first.day() == second.day() && gap < programme.required_break(earlier.venue(), later.venue()) as i64
+                })
+                .collect()
+        })
+        .collect();
+    let cost = |assignment: &[usize]| {
+        rider_events.iter().fold((0, 0), |(riders, couples), registered_events| {
+            let clashing = registered_events
+                .iter()
+                .enumerate()
+                .flat_map(|(index, first)| registered_events[index + 1..].iter().map(move |second| (first, second)))
+                .filter(|(first, second)| clashes[assignment[**first]][assignment[**second]])
+                .count();
+            (riders + usize::from(clashing > 0), couples + clashing)
+        })
+    };
+
+    let mut assignment = match match_slots(&allowed, slots.len()) {
+        Ok(assignment) => assignment,
+        Err(event) => return Err(UnschedulableEvent(events[event].clone()).into()),
+    };
+    let mut events_by_slot: Vec<Option<usize>> = vec![None; slots.len()];
+    for (event, slot) in assignment.iter().enumerate() {
+        events_by_slot[*slot] = Some(event);
+    }
+
+    let mut random = Random(seed);
+    let mut best = cost(&assignment);
+    for _ in 0..iterations {
+        if best == (0, 0) || events.is_empty() {
+            break;
+        }
+        let event = random.below(events.len());
+        let slot = allowed[event][random.below(allowed[event].len())];
+        let previous = assignment[event];
+        // The event takes the slot, its occupant if any taking the event's slot in return
+        let swapped = events_by_slot[slot];
+        if slot == previous || swapped.is_some_and(|swapped| !allowed[swapped].contains(&previous)) {
+            continue;
+        }
+        assignment[event] = slot;
+        if let Some(swapped) = swapped {
+            assignment[swapped] = previous;
+        }
+
+        // Equal moves are kept, for the search not to get stuck
+        let candidate = cost(&assignment);
+        if candidate <= best {
+            best = candidate;
+            events_by_slot[slot] = Some(event);
+            events_by_slot[previous] = swapped;
+        } else {
+            assignment[event] = previous;
+            if let Some(swapped) = swapped {
+                assignment[swapped] = slot;
+            }
+        }
+    }
+
+    let mut scheduled: Vec<Slot> = assignment
+        .iter()
+        .enumerate()
+        .map(|(event, slot)| {
+            let slot = &slots[*slot];
+            Slot::new(events[event].clone(), *slot.day(), *slot.start(), *slot.end(), slot.venue().clone())
+        })
+        .collect();
+    scheduled.sort_by(|first, second| {
+        (first.day(), first.start(), first.venue()).cmp(&(second.day(), second.start(), second.venue()))
+    });
+
+    Ok(Programme::new(scheduled, planning.transfer_times().clone(), *planning.min_break()))
+}
+
+/// Give each event an allowed slot of its own, the most constrained events first,
+/// reassigning previous events when needed. Fails with an event left without slot.
+fn match_slots(allowed: &[Vec<usize>], slots: usize) -> Result<Vec<usize>, usize> {
+    fn assign(event: usize, allowed: &[Vec<usize>], visited: &mut [bool], events_by_slot: &mut [Option<usize>]) -> bool {
+        for slot in &allowed[event] {
+            if visited[*slot] {
+                continue;
+            }
+            visited[*slot] = true;
+            let available = match events_by_slot[*slot] {
+                None => true,
+                Some(occupant) => assign(occupant, allowed, visited, events_by_slot),
+            };
+            if available {
+                events_by_slot[*slot] = Some(event);
+                return true;
+            }
+        }
+        false
+    }
+
+    let mut order: Vec<usize> = (0..allowed.len()).collect();
+    order.sort_by_key(|event| allowed[*event].len());
+    let mut events_by_slot: Vec<Option<usize>> = vec![None; slots];
+    for event in order {
+        if !assign(event, allowed, &mut vec![false; slots], &mut events_by_slot) {
+            return Err(event);
+        }
+    }
+
+    let mut assignment = vec![0; allowed.len()];
+    for (slot, event) in events_by_slot.iter().enumerate() {
+        if let Some(event) = event {
+            assignment[*event] = slot;
+        }
+    }
+    Ok(assignment)
+}
+
+/// SplitMix64, enough to draw moves without a dependency.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.0;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^= value >> 31;
+        (value % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod suggest_programme {
+        use crate::configuration::error::ConfigurationError;
+        use crate::configuration::planning::load_planning;
+        use crate::configuration::programme::{Programme, Slot};
+        use crate::error::ApplicationError;
+        use crate::statistics::schedule_conflicts::find_conflicts;
+        use crate::statistics::schedule_solver::suggest_programme;
+        use crate::registration::convention::Convention;
+        use crate::test_data::{get_test_asset, get_test_normalized_convention};
+        use std::env::temp_dir;
+        use std::fs::write;
+
+        fn clashing_riders(convention: &Convention, programme: &Programme) -> usize {
+            find_conflicts(convention, programme).unwrap().riders().len()
+        }
+
+        #[test]
+        fn success() {
+            let convention = get_test_normalized_convention();
+            let planning = load_planning(&get_test_asset("configuration/planning.yml")).unwrap();
+
+            let programme = suggest_programme(&convention, &planning, 42, 1000).unwrap();
+
+            assert_eq!(5, programme.slots().len());
+            let street = programme.slots().iter().find(|slot| slot.event() == "urbain/street").unwrap();
+            assert_eq!("2016-05-06 09:00", format!("{} {}", street.day(), street.start().format("%H:%M")));
+            // Every other placement of the four events left
+            let free_slots: Vec<usize> = vec![0, 1, 2, 3, 5];
+            let events = ["athletisme/100m", "athletisme/4x100m", "urbain/trial", "urbain/flat"];
+            let mut best = usize::MAX;
+            for first in &free_slots {
+                for second in free_slots.iter().filter(|slot| *slot != first) {
+                    for third in free_slots.iter().filter(|slot| *slot != first && *slot != second) {
+                        for fourth in free_slots.iter().filter(|slot| *slot != first && *slot != second && *slot != third) {
+                            let mut slots: Vec<Slot> = [first, second, third, fourth]
+                                .iter()
+                                .zip(events)
+                                .map(|(slot, event)| {
+                                    let slot = &planning.slots()[**slot];
+                                    Slot::new(event.to_string(), *slot.day(), *slot.start(), *slot.end(), slot.venue().clone())
+                                })
+                                .collect();
+                            slots.push(street.clone());
+                            let candidate = Programme::new(slots, planning.transfer_times().clone(), *planning.min_break());
+                            best = best.min(clashing_riders(&convention, &candidate));
+                        }
+                    }
+                }
+            }
+            assert_eq!(best, clashing_riders(&convention, &programme));
+        }
+
+        #[test]
+        fn success_deterministic() {
+            let convention = get_test_normalized_convention();
+            let planning = load_planning(&get_test_asset("configuration/planning.yml")).unwrap();
+
+            assert_eq!(
+                suggest_programme(&convention, &planning, 7, 50).unwrap(),
+                suggest_programme(&convention, &planning, 7, 50).unwrap()
+            );
+        }
+
+        #[test]
+        fn fail_unknown_event() {
+            let file = temp_dir().join("planning-unknown-event.yml");
+            write(&file, "slots: []\nevents: [urbain/unknown]").unwrap();
+            let planning = load_planning(&file).unwrap();
+
+            let error = suggest_programme(&get_test_normalized_convention(), &planning, 0, 10).unwrap_err();
+
+            assert!(matches!(error, ApplicationError::UnknownEvent(event) if event == "urbain/unknown"));
+        }
+
+        #[test]
+        fn fail_not_enough_slots() {
+            let file = temp_dir().join("planning-not-enough-slots.yml");
+            write(
+                &file,
+                "slots: [{day: 2016-05-05, start: \"09:00\", end: \"10:00\", venue: Stade}]\nevents: [athletisme/100m, urbain/flat]\nconstraints: [{event: urbain/flat, venue: Gymnase}]",
+            )
+            .unwrap();
+            let planning = load_planning(&file).unwrap();
+
+            let error = suggest_programme(&get_test_normalized_convention(), &planning, 0, 10).unwrap_err();
+
+            assert!(matches!(
+                error,
+                ApplicationError::Configuration(ConfigurationError::UnschedulableEvent(event)) if event == "urbain/flat"
+            ));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Rosters of the teams, pairs and groups of each event.
+
+use crate::registration::convention::Convention;
+use std::collections::BTreeSet;
+
+/// List the teams of every event whose participants gave team, pair or group details,
+/// as a semicolon separated table with one team per line.
+pub fn generate_csv_file(convention: &Convention) -> String {
+    let detailed_events: BTreeSet<usize> = convention
+        .team_entries()
+        .iter()
+        .flatten()
+        .map(|entry| *entry.event())
+        .collect();
+
+    let mut content = "Event;Team;Members;Unregistered members".to_string();
+    for event in detailed_events {
+        for team in convention.teams(event) {
+            let members = team
+                .members()
+                .iter()
+                .map(|member| format!("{} {}", member.first_name(), member.last_name()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            content = format!(
+                "{content}\n{};{};{members};{}",
+                convention.events()[event].name(),
+                team.name(),
+                team.unregistered_members().join(", ")
+            );
+        }
+    }
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    mod generate_csv_file {
+        use crate::statistics::team_rosters::generate_csv_file;
+        use crate::test_data::get_test_convention;
+
+        #[test]
+        fn success() {
+            let convention = get_test_convention();
+
+            let content = generate_csv_file(&convention);
+
+            let mut lines = content.lines();
+            assert_eq!(Some("Event;Team;Members;Unregistered members"), lines.next());
+            assert!(lines.all(|line| line.starts_with("Paire - All;")));
+            assert!(content.contains("\nPaire - All;Victor Bègue & Dominique Toussaint;Victor Bègue, Dominique Toussaint;\n"));
+        }
+    }
+}
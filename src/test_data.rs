@@ -3,6 +3,12 @@ use crate::registration::registrant::Registrant;
 use std::path::PathBuf;
 use crate::registration::convention::Convention;
 use crate::registration::gender::Gender;
+use crate::configuration::events_mapping::load_mappings;
+use crate::configuration::history_manifest::load_manifest;
+use crate::registration::history::ConventionHistory;
+use crate::registration::loading_options::LoadingOptions;
+use crate::registration::team::{DetailKind, TeamEntry};
+use chrono::NaiveDate;
 
 pub fn get_test_asset(asset_name: &str) -> PathBuf {
     PathBuf::from(format!(
@@ -12,66 +18,94 @@ pub fn get_test_asset(asset_name: &str) -> PathBuf {
     ))
 }
 
+pub fn birthday(birthday: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(birthday, "%d.%m.%Y").unwrap()
+}
+
+/// A registrant named `Alex Martin <id>`, born on 01.01.2000, with the details a test cares about.
+pub fn get_test_registrant(id: u16, age: u8, gender: Gender, club: Option<&str>) -> Registrant {
+    Registrant::new(id, "Alex".to_string(), format!("Martin {id}"), birthday("01.01.2000"), age, gender, club.map(str::to_string))
+}
+
+/// Two years of conventions: the test convention in 2016, and its first 30 registrants in 2017.
+pub fn get_test_history() -> ConventionHistory {
+    let manifest = load_manifest(&get_test_asset("history/manifest.yml")).unwrap();
+    ConventionHistory::load(&manifest, &LoadingOptions::default()).unwrap().0
+}
+
 pub fn get_test_convention() -> Convention {
     Convention::new(
         get_test_registrations(),
+        get_team_entries(),
         get_event_list(),
         get_participants_by_event(),
     )
 }
 
+/// The test convention, normalized with the 2016 mapping: its events are canonical ids such as `urbain/flat`.
+pub fn get_test_normalized_convention() -> Convention {
+    let mapping = PathBuf::from(format!("{}/configuration/2016.yml", env!("CARGO_MANIFEST_DIR")));
+    get_test_convention().normalize(&load_mappings(&mapping).unwrap()).unwrap()
+}
+
+fn get_team_entries() -> Vec<Vec<TeamEntry>> {
+    let mut team_entries = vec![vec![]; get_test_registrations().len()];
+    team_entries[0] = vec![TeamEntry::new(25).with_detail(DetailKind::Partner, "Dominique Toussaint".to_string())];
+    team_entries
+}
+
 fn get_test_registrations() -> Vec<(Registrant, Vec<usize>)> {
     vec![
-        (Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), vec![0, 11, 15, 17, 18, 25, 26, 28]),
-        (Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), vec![0, 2, 4, 8, 23, 25, 26]),
-        (Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), vec![6, 7, 8, 15, 17, 21, 23]),
-        (Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), vec![0, 1, 2, 6, 8, 11, 23]),
-        (Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), vec![1, 2, 5, 8, 14, 18, 26, 29]),
-        (Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), "21.05.1986".to_string(), 39, Gender::Female, Some("marché Club".to_string())), vec![6, 8, 9, 10]),
-        (Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), "28.02.2007".to_string(), 18, Gender::Male, Some("prêt Club".to_string())), vec![7, 20, 23]),
-        (Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), vec![0, 5, 9, 13, 18, 19, 21, 22, 26, 29]),
-        (Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), "15.06.1976".to_string(), 49, Gender::Male, Some("trou Club".to_string())), vec![8, 19, 25]),
-        (Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), "21.02.1970".to_string(), 55, Gender::Male, Some("glisser Club".to_string())), vec![4, 21, 23]),
-        (Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), vec![1, 4, 6, 9, 13, 22, 25, 27, 28]),
-        (Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), vec![3, 4, 6, 13, 14, 16, 20, 28]),
-        (Registrant::new(13, "Zacharie".to_string(), "Andre".to_string(), "02.08.2011".to_string(), 14, Gender::Female, Some("pouvoir Club".to_string())), vec![17, 21]),
-        (Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), vec![0, 2, 3, 4, 11, 12, 17, 22, 25, 28]),
-        (Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), vec![4, 8, 14, 17, 21, 22, 23, 24]),
-        (Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), "28.04.2003".to_string(), 22, Gender::Male, Some("bureau Club".to_string())), vec![14, 16, 21]),
-        (Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), "11.03.1988".to_string(), 37, Gender::Female, Some("discuter Club".to_string())), vec![6, 9, 14, 23, 25]),
-        (Registrant::new(18, "Claude".to_string(), "Mathieu".to_string(), "25.10.1970".to_string(), 55, Gender::Female, Some("facile Club".to_string())), vec![0]),
-        (Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), vec![0, 2, 8, 11, 14, 18, 21, 23, 28]),
-        (Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), vec![0, 1, 8, 9, 18, 19, 24]),
-        (Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), vec![1, 2, 8, 9, 12, 16, 18, 25, 27]),
-        (Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), vec![2, 7, 13, 17, 23, 24]),
-        (Registrant::new(23, "Xavier".to_string(), "Coste".to_string(), "12.05.2016".to_string(), 9, Gender::Female, Some("race Club".to_string())), vec![8, 27]),
-        (Registrant::new(24, "Roger".to_string(), "Durand".to_string(), "07.12.1995".to_string(), 30, Gender::Female, Some("créer Club".to_string())), vec![6, 8]),
-        (Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), vec![0, 3, 7, 8, 11, 21, 23, 25, 26, 29]),
-        (Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), "18.03.1998".to_string(), 27, Gender::Female, Some("nez Club".to_string())), vec![18, 22, 23]),
-        (Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), vec![0, 1, 6, 7, 12, 16, 19, 24, 25, 27]),
-        (Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), vec![0, 1, 2, 4, 7, 8, 11, 18, 28]),
-        (Registrant::new(29, "André".to_string(), "Meunier".to_string(), "20.08.1951".to_string(), 74, Gender::Male, Some("après Club".to_string())), vec![4, 8, 17, 24, 29]),
-        (Registrant::new(30, "Éléonore".to_string(), "Bonnin".to_string(), "29.11.2017".to_string(), 8, Gender::Male, Some("victime Club".to_string())), vec![10]),
-        (Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), vec![3, 4, 8, 10, 13, 16, 17, 18, 21, 25, 26]),
-        (Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), "16.10.1988".to_string(), 37, Gender::Female, Some("souffler Club".to_string())), vec![17, 22, 28, 29]),
-        (Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), vec![3, 6, 8, 11, 13, 19, 20, 22, 29]),
-        (Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), vec![0, 1, 2, 5, 8, 10, 11, 14, 22]),
-        (Registrant::new(35, "Anastasie".to_string(), "Blanchet".to_string(), "01.05.1949".to_string(), 76, Gender::Female, Some("achever Club".to_string())), vec![6, 28]),
-        (Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), vec![2, 6, 7, 8, 15, 17, 20, 28]),
-        (Registrant::new(37, "Étienne".to_string(), "Lefort".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("bon Club".to_string())), vec![8, 25]),
-        (Registrant::new(38, "Marianne".to_string(), "Rousset".to_string(), "21.08.2017".to_string(), 8, Gender::Male, Some("disparaître Club".to_string())), vec![8, 29]),
-        (Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), vec![5, 8, 9, 10, 12, 13, 18, 22, 25, 29]),
-        (Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), vec![1, 3, 6, 8, 9, 14, 21, 28, 29]),
-        (Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), vec![0, 2, 7, 8, 11, 12, 13, 14, 15, 26]),
-        (Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), "01.12.2001".to_string(), 24, Gender::Female, Some("falloir Club".to_string())), vec![11, 12, 17, 21]),
-        (Registrant::new(43, "Camille".to_string(), "Marion".to_string(), "25.04.1981".to_string(), 44, Gender::Female, Some("recherche Club".to_string())), vec![8, 14, 19, 20]),
-        (Registrant::new(44, "Danielle".to_string(), "Guichard".to_string(), "07.07.1999".to_string(), 26, Gender::Female, Some("dont Club".to_string())), vec![8, 28]),
-        (Registrant::new(45, "Andrée".to_string(), "Bigot".to_string(), "09.04.1999".to_string(), 26, Gender::Female, Some("souffler Club".to_string())), vec![8, 23]),
-        (Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string())), vec![0, 4, 9, 10, 11, 12, 13, 26, 28, 29]),
-        (Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string())), vec![1, 11, 15, 18, 20, 22, 25, 29]),
-        (Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), "13.03.1982".to_string(), 43, Gender::Male, Some("faim Club".to_string())), vec![0, 1, 8, 22, 25]),
-        (Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), "25.01.1948".to_string(), 77, Gender::Female, Some("obliger Club".to_string())), vec![1, 9, 13, 16, 27]),
-        (Registrant::new(50, "Richard".to_string(), "Gallet".to_string(), "07.08.1994".to_string(), 31, Gender::Male, Some("sur Club".to_string())), vec![9, 16])
+        (Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), vec![0, 11, 15, 17, 18, 25, 26, 28]),
+        (Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), vec![0, 2, 4, 8, 23, 25, 26]),
+        (Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), vec![6, 7, 8, 15, 17, 21, 23]),
+        (Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), vec![0, 1, 2, 6, 8, 11, 23]),
+        (Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), vec![1, 2, 5, 8, 14, 18, 26, 29]),
+        (Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), birthday("21.05.1986"), 39, Gender::Female, Some("marché Club".to_string())), vec![6, 8, 9, 10]),
+        (Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), birthday("28.02.2007"), 18, Gender::Male, Some("prêt Club".to_string())), vec![7, 20, 23]),
+        (Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), vec![0, 5, 9, 13, 18, 19, 21, 22, 26, 29]),
+        (Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), birthday("15.06.1976"), 49, Gender::Male, Some("trou Club".to_string())), vec![8, 19, 25]),
+        (Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), birthday("21.02.1970"), 55, Gender::Male, Some("glisser Club".to_string())), vec![4, 21, 23]),
+        (Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), vec![1, 4, 6, 9, 13, 22, 25, 27, 28]),
+        (Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), vec![3, 4, 6, 13, 14, 16, 20, 28]),
+        (Registrant::new(13, "Zacharie".to_string(), "Andre".to_string(), birthday("02.08.2011"), 14, Gender::Female, Some("pouvoir Club".to_string())), vec![17, 21]),
+        (Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), vec![0, 2, 3, 4, 11, 12, 17, 22, 25, 28]),
+        (Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), vec![4, 8, 14, 17, 21, 22, 23, 24]),
+        (Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), birthday("28.04.2003"), 22, Gender::Male, Some("bureau Club".to_string())), vec![14, 16, 21]),
+        (Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), birthday("11.03.1988"), 37, Gender::Female, Some("discuter Club".to_string())), vec![6, 9, 14, 23, 25]),
+        (Registrant::new(18, "Claude".to_string(), "Mathieu".to_string(), birthday("25.10.1970"), 55, Gender::Female, Some("facile Club".to_string())), vec![0]),
+        (Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), vec![0, 2, 8, 11, 14, 18, 21, 23, 28]),
+        (Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), vec![0, 1, 8, 9, 18, 19, 24]),
+        (Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), vec![1, 2, 8, 9, 12, 16, 18, 25, 27]),
+        (Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), vec![2, 7, 13, 17, 23, 24]),
+        (Registrant::new(23, "Xavier".to_string(), "Coste".to_string(), birthday("12.05.2016"), 9, Gender::Female, Some("race Club".to_string())), vec![8, 27]),
+        (Registrant::new(24, "Roger".to_string(), "Durand".to_string(), birthday("07.12.1995"), 30, Gender::Female, Some("créer Club".to_string())), vec![6, 8]),
+        (Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), vec![0, 3, 7, 8, 11, 21, 23, 25, 26, 29]),
+        (Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), birthday("18.03.1998"), 27, Gender::Female, Some("nez Club".to_string())), vec![18, 22, 23]),
+        (Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), vec![0, 1, 6, 7, 12, 16, 19, 24, 25, 27]),
+        (Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), vec![0, 1, 2, 4, 7, 8, 11, 18, 28]),
+        (Registrant::new(29, "André".to_string(), "Meunier".to_string(), birthday("20.08.1951"), 74, Gender::Male, Some("après Club".to_string())), vec![4, 8, 17, 24, 29]),
+        (Registrant::new(30, "Éléonore".to_string(), "Bonnin".to_string(), birthday("29.11.2017"), 8, Gender::Male, Some("victime Club".to_string())), vec![10]),
+        (Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), vec![3, 4, 8, 10, 13, 16, 17, 18, 21, 25, 26]),
+        (Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), birthday("16.10.1988"), 37, Gender::Female, Some("souffler Club".to_string())), vec![17, 22, 28, 29]),
+        (Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), vec![3, 6, 8, 11, 13, 19, 20, 22, 29]),
+        (Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), vec![0, 1, 2, 5, 8, 10, 11, 14, 22]),
+        (Registrant::new(35, "Anastasie".to_string(), "Blanchet".to_string(), birthday("01.05.1949"), 76, Gender::Female, Some("achever Club".to_string())), vec![6, 28]),
+        (Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), vec![2, 6, 7, 8, 15, 17, 20, 28]),
+        (Registrant::new(37, "Étienne".to_string(), "Lefort".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("bon Club".to_string())), vec![8, 25]),
+        (Registrant::new(38, "Marianne".to_string(), "Rousset".to_string(), birthday("21.08.2017"), 8, Gender::Male, Some("disparaître Club".to_string())), vec![8, 29]),
+        (Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), vec![5, 8, 9, 10, 12, 13, 18, 22, 25, 29]),
+        (Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), vec![1, 3, 6, 8, 9, 14, 21, 28, 29]),
+        (Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), vec![0, 2, 7, 8, 11, 12, 13, 14, 15, 26]),
+        (Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), birthday("01.12.2001"), 24, Gender::Female, Some("falloir Club".to_string())), vec![11, 12, 17, 21]),
+        (Registrant::new(43, "Camille".to_string(), "Marion".to_string(), birthday("25.04.1981"), 44, Gender::Female, Some("recherche Club".to_string())), vec![8, 14, 19, 20]),
+        (Registrant::new(44, "Danielle".to_string(), "Guichard".to_string(), birthday("07.07.1999"), 26, Gender::Female, Some("dont Club".to_string())), vec![8, 28]),
+        (Registrant::new(45, "Andrée".to_string(), "Bigot".to_string(), birthday("09.04.1999"), 26, Gender::Female, Some("souffler Club".to_string())), vec![8, 23]),
+        (Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string())), vec![0, 4, 9, 10, 11, 12, 13, 26, 28, 29]),
+        (Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string())), vec![1, 11, 15, 18, 20, 22, 25, 29]),
+        (Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), birthday("13.03.1982"), 43, Gender::Male, Some("faim Club".to_string())), vec![0, 1, 8, 22, 25]),
+        (Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), birthday("25.01.1948"), 77, Gender::Female, Some("obliger Club".to_string())), vec![1, 9, 13, 16, 27]),
+        (Registrant::new(50, "Richard".to_string(), "Gallet".to_string(), birthday("07.08.1994"), 31, Gender::Male, Some("sur Club".to_string())), vec![9, 16])
     ]
 }
 
@@ -112,35 +146,35 @@ fn get_event_list() -> Vec<Event> {
 
 fn get_participants_by_event() -> Vec<Vec<Registrant>> {
     vec![
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(18, "Claude".to_string(), "Mathieu".to_string(), "25.10.1970".to_string(), 55, Gender::Female, Some("facile Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), "13.03.1982".to_string(), 43, Gender::Male, Some("faim Club".to_string()))],
-        vec![Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), "13.03.1982".to_string(), 43, Gender::Male, Some("faim Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), "25.01.1948".to_string(), 77, Gender::Female, Some("obliger Club".to_string()))],
-        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string()))],
-        vec![Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string()))],
-        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), "21.02.1970".to_string(), 55, Gender::Male, Some("glisser Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), "20.08.1951".to_string(), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string()))],
-        vec![Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string()))],
-        vec![Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), "21.05.1986".to_string(), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), "11.03.1988".to_string(), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(24, "Roger".to_string(), "Durand".to_string(), "07.12.1995".to_string(), 30, Gender::Female, Some("créer Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(35, "Anastasie".to_string(), "Blanchet".to_string(), "01.05.1949".to_string(), 76, Gender::Female, Some("achever Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string()))],
-        vec![Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), "28.02.2007".to_string(), 18, Gender::Male, Some("prêt Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string()))],
-        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), "21.05.1986".to_string(), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), "15.06.1976".to_string(), 49, Gender::Male, Some("trou Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(23, "Xavier".to_string(), "Coste".to_string(), "12.05.2016".to_string(), 9, Gender::Female, Some("race Club".to_string())), Registrant::new(24, "Roger".to_string(), "Durand".to_string(), "07.12.1995".to_string(), 30, Gender::Female, Some("créer Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), "20.08.1951".to_string(), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(37, "Étienne".to_string(), "Lefort".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("bon Club".to_string())), Registrant::new(38, "Marianne".to_string(), "Rousset".to_string(), "21.08.2017".to_string(), 8, Gender::Male, Some("disparaître Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), "25.04.1981".to_string(), 44, Gender::Female, Some("recherche Club".to_string())), Registrant::new(44, "Danielle".to_string(), "Guichard".to_string(), "07.07.1999".to_string(), 26, Gender::Female, Some("dont Club".to_string())), Registrant::new(45, "Andrée".to_string(), "Bigot".to_string(), "09.04.1999".to_string(), 26, Gender::Female, Some("souffler Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), "13.03.1982".to_string(), 43, Gender::Male, Some("faim Club".to_string()))],
-        vec![Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), "21.05.1986".to_string(), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), "11.03.1988".to_string(), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), "25.01.1948".to_string(), 77, Gender::Female, Some("obliger Club".to_string())), Registrant::new(50, "Richard".to_string(), "Gallet".to_string(), "07.08.1994".to_string(), 31, Gender::Male, Some("sur Club".to_string()))],
-        vec![Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), "21.05.1986".to_string(), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(30, "Éléonore".to_string(), "Bonnin".to_string(), "29.11.2017".to_string(), 8, Gender::Male, Some("victime Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), "01.12.2001".to_string(), 24, Gender::Female, Some("falloir Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string()))],
-        vec![Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), "01.12.2001".to_string(), 24, Gender::Female, Some("falloir Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string()))],
-        vec![Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), "25.01.1948".to_string(), 77, Gender::Female, Some("obliger Club".to_string()))],
-        vec![Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), "28.04.2003".to_string(), 22, Gender::Male, Some("bureau Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), "11.03.1988".to_string(), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), "25.04.1981".to_string(), 44, Gender::Female, Some("recherche Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string()))],
-        vec![Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), "28.04.2003".to_string(), 22, Gender::Male, Some("bureau Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), "25.01.1948".to_string(), 77, Gender::Female, Some("obliger Club".to_string())), Registrant::new(50, "Richard".to_string(), "Gallet".to_string(), "07.08.1994".to_string(), 31, Gender::Male, Some("sur Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(13, "Zacharie".to_string(), "Andre".to_string(), "02.08.2011".to_string(), 14, Gender::Female, Some("pouvoir Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), "20.08.1951".to_string(), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), "16.10.1988".to_string(), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), "01.12.2001".to_string(), 24, Gender::Female, Some("falloir Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), "18.03.1998".to_string(), 27, Gender::Female, Some("nez Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string()))],
-        vec![Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), "15.06.1976".to_string(), 49, Gender::Male, Some("trou Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), "25.04.1981".to_string(), 44, Gender::Female, Some("recherche Club".to_string()))],
-        vec![Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), "28.02.2007".to_string(), 18, Gender::Male, Some("prêt Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), "25.04.1981".to_string(), 44, Gender::Female, Some("recherche Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string()))],
-        vec![Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), "21.02.1970".to_string(), 55, Gender::Male, Some("glisser Club".to_string())), Registrant::new(13, "Zacharie".to_string(), "Andre".to_string(), "02.08.2011".to_string(), 14, Gender::Female, Some("pouvoir Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), "28.04.2003".to_string(), 22, Gender::Male, Some("bureau Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), "01.12.2001".to_string(), 24, Gender::Female, Some("falloir Club".to_string()))],
-        vec![Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), "18.03.1998".to_string(), 27, Gender::Female, Some("nez Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), "16.10.1988".to_string(), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), "28.07.1994".to_string(), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), "13.03.1982".to_string(), 43, Gender::Male, Some("faim Club".to_string()))],
-        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), "30.06.1984".to_string(), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), "29.01.1958".to_string(), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), "28.02.2007".to_string(), 18, Gender::Male, Some("prêt Club".to_string())), Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), "21.02.1970".to_string(), 55, Gender::Male, Some("glisser Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), "11.03.1988".to_string(), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), "18.03.1998".to_string(), 27, Gender::Female, Some("nez Club".to_string())), Registrant::new(45, "Andrée".to_string(), "Bigot".to_string(), "09.04.1999".to_string(), 26, Gender::Female, Some("souffler Club".to_string()))],
-        vec![Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), "05.02.1990".to_string(), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), "12.08.1981".to_string(), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), "16.11.2002".to_string(), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), "20.08.1951".to_string(), 74, Gender::Male, Some("après Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), "15.06.1976".to_string(), 49, Gender::Male, Some("trou Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), "11.03.1988".to_string(), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(37, "Étienne".to_string(), "Lefort".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("bon Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), "13.03.1982".to_string(), 43, Gender::Male, Some("faim Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), "20.06.1971".to_string(), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), "19.10.1965".to_string(), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), "31.12.1976".to_string(), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string()))],
-        vec![Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), "16.12.1983".to_string(), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(23, "Xavier".to_string(), "Coste".to_string(), "12.05.2016".to_string(), 9, Gender::Female, Some("race Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), "12.05.2001".to_string(), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), "25.01.1948".to_string(), 77, Gender::Female, Some("obliger Club".to_string()))],
-        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), "31.12.1966".to_string(), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), "06.11.2009".to_string(), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), "09.07.1951".to_string(), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), "19.06.2017".to_string(), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), "09.11.1988".to_string(), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), "06.09.1966".to_string(), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), "16.10.1988".to_string(), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(35, "Anastasie".to_string(), "Blanchet".to_string(), "01.05.1949".to_string(), 76, Gender::Female, Some("achever Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), "26.07.1984".to_string(), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(44, "Danielle".to_string(), "Guichard".to_string(), "07.07.1999".to_string(), 26, Gender::Female, Some("dont Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string()))],
-        vec![Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), "28.08.1964".to_string(), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), "09.03.1948".to_string(), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), "29.10.1988".to_string(), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), "20.08.1951".to_string(), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), "16.10.1988".to_string(), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), "06.04.2003".to_string(), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(38, "Marianne".to_string(), "Rousset".to_string(), "21.08.2017".to_string(), 8, Gender::Male, Some("disparaître Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), "27.10.1957".to_string(), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), "06.03.2007".to_string(), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), "03.03.1962".to_string(), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), "14.10.2002".to_string(), 23, Gender::Female, Some("voie Club".to_string()))]
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(18, "Claude".to_string(), "Mathieu".to_string(), birthday("25.10.1970"), 55, Gender::Female, Some("facile Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), birthday("13.03.1982"), 43, Gender::Male, Some("faim Club".to_string()))],
+        vec![Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), birthday("13.03.1982"), 43, Gender::Male, Some("faim Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), birthday("25.01.1948"), 77, Gender::Female, Some("obliger Club".to_string()))],
+        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string()))],
+        vec![Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string()))],
+        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), birthday("21.02.1970"), 55, Gender::Male, Some("glisser Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), birthday("20.08.1951"), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string()))],
+        vec![Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string()))],
+        vec![Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), birthday("21.05.1986"), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), birthday("11.03.1988"), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(24, "Roger".to_string(), "Durand".to_string(), birthday("07.12.1995"), 30, Gender::Female, Some("créer Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(35, "Anastasie".to_string(), "Blanchet".to_string(), birthday("01.05.1949"), 76, Gender::Female, Some("achever Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string()))],
+        vec![Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), birthday("28.02.2007"), 18, Gender::Male, Some("prêt Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string()))],
+        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), birthday("21.05.1986"), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), birthday("15.06.1976"), 49, Gender::Male, Some("trou Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(23, "Xavier".to_string(), "Coste".to_string(), birthday("12.05.2016"), 9, Gender::Female, Some("race Club".to_string())), Registrant::new(24, "Roger".to_string(), "Durand".to_string(), birthday("07.12.1995"), 30, Gender::Female, Some("créer Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), birthday("20.08.1951"), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(37, "Étienne".to_string(), "Lefort".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("bon Club".to_string())), Registrant::new(38, "Marianne".to_string(), "Rousset".to_string(), birthday("21.08.2017"), 8, Gender::Male, Some("disparaître Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), birthday("25.04.1981"), 44, Gender::Female, Some("recherche Club".to_string())), Registrant::new(44, "Danielle".to_string(), "Guichard".to_string(), birthday("07.07.1999"), 26, Gender::Female, Some("dont Club".to_string())), Registrant::new(45, "Andrée".to_string(), "Bigot".to_string(), birthday("09.04.1999"), 26, Gender::Female, Some("souffler Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), birthday("13.03.1982"), 43, Gender::Male, Some("faim Club".to_string()))],
+        vec![Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), birthday("21.05.1986"), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), birthday("11.03.1988"), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), birthday("25.01.1948"), 77, Gender::Female, Some("obliger Club".to_string())), Registrant::new(50, "Richard".to_string(), "Gallet".to_string(), birthday("07.08.1994"), 31, Gender::Male, Some("sur Club".to_string()))],
+        vec![Registrant::new(6, "Louis".to_string(), "Couturier".to_string(), birthday("21.05.1986"), 39, Gender::Female, Some("marché Club".to_string())), Registrant::new(30, "Éléonore".to_string(), "Bonnin".to_string(), birthday("29.11.2017"), 8, Gender::Male, Some("victime Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), birthday("01.12.2001"), 24, Gender::Female, Some("falloir Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string()))],
+        vec![Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), birthday("01.12.2001"), 24, Gender::Female, Some("falloir Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string()))],
+        vec![Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), birthday("25.01.1948"), 77, Gender::Female, Some("obliger Club".to_string()))],
+        vec![Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), birthday("28.04.2003"), 22, Gender::Male, Some("bureau Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), birthday("11.03.1988"), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), birthday("25.04.1981"), 44, Gender::Female, Some("recherche Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string()))],
+        vec![Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), birthday("28.04.2003"), 22, Gender::Male, Some("bureau Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), birthday("25.01.1948"), 77, Gender::Female, Some("obliger Club".to_string())), Registrant::new(50, "Richard".to_string(), "Gallet".to_string(), birthday("07.08.1994"), 31, Gender::Male, Some("sur Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(13, "Zacharie".to_string(), "Andre".to_string(), birthday("02.08.2011"), 14, Gender::Female, Some("pouvoir Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), birthday("20.08.1951"), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), birthday("16.10.1988"), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), birthday("01.12.2001"), 24, Gender::Female, Some("falloir Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), birthday("18.03.1998"), 27, Gender::Female, Some("nez Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string()))],
+        vec![Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), birthday("15.06.1976"), 49, Gender::Male, Some("trou Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), birthday("25.04.1981"), 44, Gender::Female, Some("recherche Club".to_string()))],
+        vec![Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), birthday("28.02.2007"), 18, Gender::Male, Some("prêt Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(43, "Camille".to_string(), "Marion".to_string(), birthday("25.04.1981"), 44, Gender::Female, Some("recherche Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string()))],
+        vec![Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), birthday("21.02.1970"), 55, Gender::Male, Some("glisser Club".to_string())), Registrant::new(13, "Zacharie".to_string(), "Andre".to_string(), birthday("02.08.2011"), 14, Gender::Female, Some("pouvoir Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(16, "Michèle".to_string(), "Traore".to_string(), birthday("28.04.2003"), 22, Gender::Male, Some("bureau Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(42, "Océane".to_string(), "Gomes".to_string(), birthday("01.12.2001"), 24, Gender::Female, Some("falloir Club".to_string()))],
+        vec![Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), birthday("18.03.1998"), 27, Gender::Female, Some("nez Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), birthday("16.10.1988"), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(34, "Georges".to_string(), "Pereira".to_string(), birthday("28.07.1994"), 31, Gender::Female, Some("agir Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), birthday("13.03.1982"), 43, Gender::Male, Some("faim Club".to_string()))],
+        vec![Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(3, "Sébastien".to_string(), "Mace".to_string(), birthday("30.06.1984"), 41, Gender::Male, Some("je Club".to_string())), Registrant::new(4, "Laurent".to_string(), "Lelièvre".to_string(), birthday("29.01.1958"), 67, Gender::Female, Some("voile Club".to_string())), Registrant::new(7, "Éléonore".to_string(), "Faure".to_string(), birthday("28.02.2007"), 18, Gender::Male, Some("prêt Club".to_string())), Registrant::new(10, "Guillaume".to_string(), "Nguyen".to_string(), birthday("21.02.1970"), 55, Gender::Male, Some("glisser Club".to_string())), Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), birthday("11.03.1988"), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(26, "Richard".to_string(), "Dubois".to_string(), birthday("18.03.1998"), 27, Gender::Female, Some("nez Club".to_string())), Registrant::new(45, "Andrée".to_string(), "Bigot".to_string(), birthday("09.04.1999"), 26, Gender::Female, Some("souffler Club".to_string()))],
+        vec![Registrant::new(15, "Brigitte".to_string(), "Antoine".to_string(), birthday("05.02.1990"), 35, Gender::Female, Some("passion Club".to_string())), Registrant::new(20, "Éléonore".to_string(), "Dupré".to_string(), birthday("12.08.1981"), 44, Gender::Male, Some("tâche Club".to_string())), Registrant::new(22, "Sabine".to_string(), "Leroux".to_string(), birthday("16.11.2002"), 23, Gender::Male, Some("ici Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), birthday("20.08.1951"), 74, Gender::Male, Some("après Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(9, "Émile".to_string(), "Jourdan".to_string(), birthday("15.06.1976"), 49, Gender::Male, Some("trou Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(17, "Anouk".to_string(), "Aubert".to_string(), birthday("11.03.1988"), 37, Gender::Female, Some("discuter Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(37, "Étienne".to_string(), "Lefort".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("bon Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string())), Registrant::new(48, "Colette".to_string(), "Descamps".to_string(), birthday("13.03.1982"), 43, Gender::Male, Some("faim Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(2, "Dominique".to_string(), "Toussaint".to_string(), birthday("20.06.1971"), 54, Gender::Male, Some("intérieur Club".to_string())), Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(31, "Aurélie".to_string(), "Dufour".to_string(), birthday("19.10.1965"), 60, Gender::Female, Some("côté Club".to_string())), Registrant::new(41, "Hugues".to_string(), "Fouquet".to_string(), birthday("31.12.1976"), 49, Gender::Female, Some("très Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string()))],
+        vec![Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(21, "Nicolas".to_string(), "Dijoux".to_string(), birthday("16.12.1983"), 42, Gender::Male, Some("demain Club".to_string())), Registrant::new(23, "Xavier".to_string(), "Coste".to_string(), birthday("12.05.2016"), 9, Gender::Female, Some("race Club".to_string())), Registrant::new(27, "Frédérique".to_string(), "Tanguy".to_string(), birthday("12.05.2001"), 24, Gender::Female, Some("tout Club".to_string())), Registrant::new(49, "Laurent".to_string(), "Louis".to_string(), birthday("25.01.1948"), 77, Gender::Female, Some("obliger Club".to_string()))],
+        vec![Registrant::new(1, "Victor".to_string(), "Bègue".to_string(), birthday("31.12.1966"), 59, Gender::Male, Some("vivant Club".to_string())), Registrant::new(11, "Marine".to_string(), "Poulain".to_string(), birthday("06.11.2009"), 16, Gender::Male, Some("refuser Club".to_string())), Registrant::new(12, "Aimée".to_string(), "Le Roux".to_string(), birthday("09.07.1951"), 74, Gender::Female, Some("grand Club".to_string())), Registrant::new(14, "Sophie".to_string(), "Carre".to_string(), birthday("19.06.2017"), 8, Gender::Female, Some("neuf Club".to_string())), Registrant::new(19, "Michelle".to_string(), "Gosselin".to_string(), birthday("09.11.1988"), 37, Gender::Female, Some("rang Club".to_string())), Registrant::new(28, "Honoré".to_string(), "Paul".to_string(), birthday("06.09.1966"), 59, Gender::Female, Some("afin de Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), birthday("16.10.1988"), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(35, "Anastasie".to_string(), "Blanchet".to_string(), birthday("01.05.1949"), 76, Gender::Female, Some("achever Club".to_string())), Registrant::new(36, "Céline".to_string(), "Andre".to_string(), birthday("26.07.1984"), 41, Gender::Male, Some("drame Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(44, "Danielle".to_string(), "Guichard".to_string(), birthday("07.07.1999"), 26, Gender::Female, Some("dont Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string()))],
+        vec![Registrant::new(5, "Christophe".to_string(), "Poirier".to_string(), birthday("28.08.1964"), 61, Gender::Male, Some("juge Club".to_string())), Registrant::new(8, "Bernadette".to_string(), "Dupuis".to_string(), birthday("09.03.1948"), 77, Gender::Female, Some("force Club".to_string())), Registrant::new(25, "Benoît".to_string(), "Alves".to_string(), birthday("29.10.1988"), 37, Gender::Female, Some("paraître Club".to_string())), Registrant::new(29, "André".to_string(), "Meunier".to_string(), birthday("20.08.1951"), 74, Gender::Male, Some("après Club".to_string())), Registrant::new(32, "Adrienne".to_string(), "Bertin".to_string(), birthday("16.10.1988"), 37, Gender::Female, Some("souffler Club".to_string())), Registrant::new(33, "Sabine".to_string(), "Maillet".to_string(), birthday("06.04.2003"), 22, Gender::Male, Some("lire Club".to_string())), Registrant::new(38, "Marianne".to_string(), "Rousset".to_string(), birthday("21.08.2017"), 8, Gender::Male, Some("disparaître Club".to_string())), Registrant::new(39, "Jeannine".to_string(), "Bouvet".to_string(), birthday("27.10.1957"), 68, Gender::Female, Some("mon Club".to_string())), Registrant::new(40, "Sébastien".to_string(), "Girard".to_string(), birthday("06.03.2007"), 18, Gender::Male, Some("puis Club".to_string())), Registrant::new(46, "Marianne".to_string(), "Étienne".to_string(), birthday("03.03.1962"), 63, Gender::Female, Some("appeler Club".to_string())), Registrant::new(47, "Anastasie".to_string(), "Gomes".to_string(), birthday("14.10.2002"), 23, Gender::Female, Some("voie Club".to_string()))]
     ]
 }